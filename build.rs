@@ -0,0 +1,80 @@
+//! Generates one test per example in `examples/day<n>/`, see
+//! `tests/examples.rs`.
+//!
+//! An example that cannot be checked, such as one without expected answers,
+//! becomes a test that fails and a warning, so that it does not stop the
+//! crate from building.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    println!("cargo::rerun-if-changed={}", root.display());
+
+    let mut examples = vec![];
+    let mut tests = String::new();
+    for dir in fs::read_dir(&root).into_iter().flatten().flatten() {
+        let Some(day) = dir
+            .file_name()
+            .to_str()
+            .and_then(|n| n.strip_prefix("day"))
+            .and_then(|n| n.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        let files = match fs::read_dir(dir.path()) {
+            Ok(files) => files,
+            Err(e) => {
+                let message = format!("cannot read {}: {e}", dir.path().display());
+                failing_test(&mut tests, &format!("day{day}_examples"), &message);
+                continue;
+            }
+        };
+        for file in files.flatten() {
+            let path = file.path();
+            if path.extension().is_some_and(|e| e == "in") {
+                examples.push((day, path));
+            }
+        }
+    }
+    examples.sort();
+
+    for (day, input) in &examples {
+        let name = input
+            .file_stem()
+            .unwrap()
+            .to_string_lossy()
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        let expected = input.with_extension("out");
+        if !expected.exists() {
+            let message = format!("{} has no {}", input.display(), expected.display());
+            failing_test(&mut tests, &format!("day{day}_{name}"), &message);
+            continue;
+        }
+
+        writeln!(
+            tests,
+            "#[test]\nfn day{day}_{name}() {{\n    \
+             check({day}, include_str!({input:?}), include_str!({expected:?}));\n}}\n"
+        )
+        .unwrap();
+    }
+
+    let mut days = examples.iter().map(|(d, _)| *d).collect::<Vec<_>>();
+    days.dedup();
+    writeln!(tests, "const EXAMPLE_DAYS: &[u8] = &{days:?};").unwrap();
+
+    let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// Adds a test called `name` that fails with `message`, and warns about it.
+fn failing_test(tests: &mut String, name: &str, message: &str) {
+    println!("cargo::warning={message}");
+    writeln!(
+        tests,
+        "#[test]\nfn {name}() {{\n    panic!({message:?});\n}}\n"
+    )
+    .unwrap();
+}
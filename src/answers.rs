@@ -0,0 +1,173 @@
+//! Known answers, keyed by day, part and input, to catch regressions.
+//!
+//! The answers file has one answer per line, preceded by its day, part and
+//! the [`input_hash`] of the input it belongs to:
+//!
+//! ```text
+//! # day part input answer
+//! 1 1 a1b2c3d4e5f60718 1092
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Result, records};
+
+/// Environment variable naming the answers file.
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+/// The answers file: `$AOC_ANSWERS`, or `answers` in the repository.
+pub fn default_path() -> PathBuf {
+    env::var_os(ANSWERS_VAR)
+        .filter(|p| !p.is_empty())
+        .map_or_else(
+            || Path::new(env!("CARGO_MANIFEST_DIR")).join("answers"),
+            PathBuf::from,
+        )
+}
+
+/// 64-bit FNV-1a hash of `input`, which identifies an input across runs and
+/// machines.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, u8, u64), String>,
+}
+
+impl Answers {
+    /// Reads the answers file at `path`. A missing file has no answers.
+    pub fn load(path: &Path) -> Result<Self> {
+        records::load(path, Self::parse)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    fn parse(s: &str) -> Result<Self, (usize, String)> {
+        let mut entries = BTreeMap::new();
+
+        records::parse(s, |mut fields| {
+            let day = fields.parse("day")?;
+            let part = fields.parse("part")?;
+            let hash = fields.map("input hash", |h| u64::from_str_radix(h, 16))?;
+            let answer = fields.rest("answer")?;
+
+            entries.insert((day, part, hash), answer);
+            Ok(())
+        })?;
+
+        Ok(Answers { entries })
+    }
+
+    pub fn get(&self, day: u8, part: u8, hash: u64) -> Option<&str> {
+        self.entries.get(&(day, part, hash)).map(String::as_str)
+    }
+
+    /// Records `answer`, returning the previously known one, if any.
+    pub fn insert(&mut self, day: u8, part: u8, hash: u64, answer: String) -> Option<String> {
+        self.entries.insert((day, part, hash), answer)
+    }
+
+    pub fn check(&self, day: u8, part: u8, hash: u64, answer: &str) -> Verdict {
+        match self.get(day, part, hash) {
+            Some(a) if a == answer => Verdict::Pass,
+            Some(a) => Verdict::Fail {
+                expected: a.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part input answer")?;
+        self.entries
+            .iter()
+            .try_for_each(|((day, part, hash), answer)| {
+                writeln!(f, "{day} {part} {hash:016x} {answer}")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(input_hash("L68\n"), input_hash("L68"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut a = Answers::default();
+        a.insert(1, 1, 0xabc, "1092".to_owned());
+        a.insert(1, 2, 0xabc, "6616".to_owned());
+        a.insert(9, 1, 0xdef, "some text".to_owned());
+
+        assert_eq!(Answers::parse(&a.to_string()), Ok(a));
+    }
+
+    #[test]
+    fn test_check() {
+        let a = Answers::parse("# comment\n\n1 2 00000000000000ff 6\n").unwrap();
+
+        assert_eq!(a.check(1, 2, 0xff, "6"), Verdict::Pass);
+        assert_eq!(
+            a.check(1, 2, 0xff, "7"),
+            Verdict::Fail {
+                expected: "6".to_owned()
+            }
+        );
+        assert_eq!(a.check(1, 1, 0xff, "6"), Verdict::Unknown);
+        assert_eq!(a.check(1, 2, 0xfe, "6"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Answers::parse("1 2 ff\n"),
+            Err((1, "missing answer".to_owned()))
+        );
+        assert_eq!(
+            Answers::parse("\n1 x ff 3\n"),
+            Err((2, "bad part".to_owned()))
+        );
+        assert_eq!(
+            Answers::parse("1 2 fg 3\n"),
+            Err((1, "bad input hash".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Timing of the parse and solve phases of a day.
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Error, Result, days::Day};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Untimed runs before the timed ones.
+    pub warmup: u32,
+    /// Timed runs; at least one is always made.
+    pub runs: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Stats,
+    /// Timings of both parts; `None` for parts that are not solved yet.
+    pub parts: [Option<Stats>; 2],
+}
+
+/// Times parsing `input` and solving each part of `day` separately.
+pub fn bench(day: &Day, input: &str, opts: &Options) -> Result<Report> {
+    let parse = time(opts, || day.parse(black_box(input)))?;
+    let parsed = day.parse(input)?;
+    let part = |p| match time(opts, || day.solve(p, black_box(&parsed))) {
+        Ok(s) => Ok(Some(s)),
+        Err(Error::Unsolved) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    Ok(Report {
+        day: day.number,
+        parse,
+        parts: [part(1)?, part(2)?],
+    })
+}
+
+fn time<T>(opts: &Options, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..opts.warmup {
+        black_box(f()?);
+    }
+
+    let samples = (0..opts.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            let r = f()?;
+            let elapsed = start.elapsed();
+            black_box(r);
+            Ok(elapsed)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Stats::new(samples))
+}
+
+/// Formats `reports` as an aligned table with one row per phase.
+pub fn to_text(reports: &[Report]) -> String {
+    let mut s = format!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}\n",
+        "day", "phase", "min", "median", "max"
+    );
+
+    for r in reports {
+        for (phase, stats) in phases(r) {
+            let _ = match stats {
+                Some(t) => writeln!(
+                    s,
+                    "{:>3}  {phase:<6}  {:>10}  {:>10}  {:>10}",
+                    r.day,
+                    fmt_duration(t.min),
+                    fmt_duration(t.median),
+                    fmt_duration(t.max)
+                ),
+                None => writeln!(s, "{:>3}  {phase:<6}  {:>10}", r.day, "unsolved"),
+            };
+        }
+    }
+
+    s
+}
+
+/// Formats `reports` as a JSON array with one object per day. Durations are
+/// in nanoseconds; unsolved parts are `null`.
+pub fn to_json(reports: &[Report]) -> String {
+    let days = reports
+        .iter()
+        .map(|r| {
+            let phases = phases(r)
+                .map(|(phase, stats)| match stats {
+                    Some(t) => format!(
+                        r#""{phase}":{{"min_ns":{},"median_ns":{},"max_ns":{}}}"#,
+                        t.min.as_nanos(),
+                        t.median.as_nanos(),
+                        t.max.as_nanos()
+                    ),
+                    None => format!(r#""{phase}":null"#),
+                })
+                .collect::<Vec<_>>();
+            format!(r#"{{"day":{},{}}}"#, r.day, phases.join(","))
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", days.join(","))
+}
+
+fn phases(r: &Report) -> impl Iterator<Item = (&'static str, Option<Stats>)> {
+    [
+        ("parse", Some(r.parse)),
+        ("part_1", r.parts[0]),
+        ("part_2", r.parts[1]),
+    ]
+    .into_iter()
+}
+
+fn fmt_duration(d: Duration) -> String {
+    match d.as_nanos() {
+        n if n < 1_000 => format!("{n}ns"),
+        n if n < 1_000_000 => format!("{:.2}µs", n as f64 / 1e3),
+        n if n < 1_000_000_000 => format!("{:.2}ms", n as f64 / 1e6),
+        n => format!("{:.2}s", n as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(
+            Stats::new(vec![ms(5), ms(1), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+        assert_eq!(Stats::new(vec![ms(4), ms(1), ms(2), ms(9)]).median, ms(3));
+        assert_eq!(Stats::new(vec![ms(7)]).median, ms(7));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let t = Stats {
+            min: Duration::from_nanos(1),
+            median: Duration::from_nanos(2),
+            max: Duration::from_nanos(3),
+        };
+        let r = Report {
+            day: 9,
+            parse: t,
+            parts: [Some(t), None],
+        };
+
+        assert_eq!(
+            to_json(&[r]),
+            concat!(
+                r#"[{"day":9,"parse":{"min_ns":1,"median_ns":2,"max_ns":3},"#,
+                r#""part_1":{"min_ns":1,"median_ns":2,"max_ns":3},"part_2":null}]"#
+            )
+        );
+    }
+}
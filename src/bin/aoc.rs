@@ -0,0 +1,765 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use aoc_2025::{
+    Error, Params,
+    answers::{self, Answers, Verdict, input_hash},
+    bench,
+    days::{self, DAYS, Day},
+    guesses::{self, Feedback, Guesses},
+    input, read_input,
+    report::{self, Format},
+    runner::{self, Job},
+    scaffold,
+    site::{Fetched, Site},
+    watch::Watcher,
+};
+
+const USAGE: &str = "\
+usage: aoc <day> [--part <1|2>] [--param <name>=<value>]... [--jobs <n>]
+           [--format <f> | --trace] [<input> | -]
+       aoc --all [--jobs <n>] [--format <f>]
+       aoc verify [--record] [<day>...]
+       aoc bench [--warmup <n>] [--runs <n>] [--json] [<day>...]
+       aoc fetch [--base-url <url>] [<day>...]
+       aoc submit [--base-url <url>] <day> <1|2> [<answer>]
+       aoc new <day>
+       aoc watch [--interval <ms>] <day> [<input>]
+
+Solves the given day using the input at <input>, or standard input for `-`.
+Without <input>, the first existing file of $AOC_INPUT_DIR/day<day>,
+<data dir>/aoc-2025/day<day> and input/day<day> in the repository is used.
+--param sets a parameter of the day, such as size=<n> or start=<n> for the
+dial of day 1. --all solves every implemented day. Days, and the parts of a
+day, are solved on <n> threads (one per CPU by default), but always reported
+in order. --format json, csv or tsv prints a record with the answer, status,
+timings and input hash of every part instead of plain answers. --trace
+prints how the day arrives at its answers before them, for days that can
+show it, such as the dial of day 1 (and its face with --param face=true).
+
+verify checks the answers for the given days, or all days, against the
+answers file ($AOC_ANSWERS, or answers in the repository) and prints PASS,
+FAIL or UNKNOWN for each part. --record adds the UNKNOWN answers to the file.
+
+bench times parsing and both parts of the given days, or all days, over
+<n> runs (10 by default) after <n> warmup runs (3 by default) and reports the
+minimum, median and maximum as a table or as JSON.
+
+fetch downloads the inputs of the given days, or all implemented days, that
+are not in any of the locations above yet, and saves them to the first one.
+It needs the session cookie of the website in $AOC_SESSION or in the file
+$AOC_SESSION_FILE (<data dir>/aoc-2025/session by default). The website is
+at --base-url, $AOC_BASE_URL or https://adventofcode.com.
+
+submit sends <answer>, or the answer computed from the input, to the website
+and records the verdict in the guesses file ($AOC_GUESSES, or
+<data dir>/aoc-2025/guesses). Answers that were guessed before, or that are
+not between the highest guess that was too low and the lowest guess that was
+too high, are refused without asking the website.
+
+new creates src/days/day<day>.rs with a skeleton solution, a placeholder
+example in examples/day<day>/ and registers the day in src/days.rs. Existing
+days are never overwritten.
+
+watch solves the day, and its examples in examples/day<day>/, again whenever
+the input or an example changes, checking every <ms> milliseconds (500 by
+default), and shows which answers changed. Changes to the source need a
+rebuild.";
+
+enum Command {
+    Day {
+        day: &'static Day,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+        params: Params,
+        opts: RunOptions,
+        trace: bool,
+    },
+    All {
+        opts: RunOptions,
+    },
+    Verify {
+        days: Vec<&'static Day>,
+        record: bool,
+    },
+    Bench {
+        days: Vec<&'static Day>,
+        opts: bench::Options,
+        json: bool,
+    },
+    Fetch {
+        days: Vec<u8>,
+        base_url: Option<String>,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        answer: Option<String>,
+        base_url: Option<String>,
+    },
+    New {
+        day: u8,
+    },
+    Watch {
+        day: &'static Day,
+        input: Option<PathBuf>,
+        interval: Duration,
+    },
+    Help,
+}
+
+struct RunOptions {
+    threads: usize,
+    /// Plain answers if `None`.
+    format: Option<Format>,
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let ok = match command {
+        Command::Day {
+            day,
+            part,
+            input,
+            params,
+            opts,
+            trace,
+        } => {
+            let parts = part.map_or(vec![1, 2], |p| vec![p]);
+            let job = Job {
+                day,
+                parts: &parts,
+                input: input.as_deref(),
+                params: &params,
+            };
+            if trace {
+                run_trace(&job)
+            } else {
+                run(&[job], &opts, false)
+            }
+        }
+        Command::All { opts } => {
+            let params = Params::default();
+            let jobs = DAYS
+                .iter()
+                .map(|day| Job {
+                    day,
+                    parts: &[1, 2],
+                    input: None,
+                    params: &params,
+                })
+                .collect::<Vec<_>>();
+            run(&jobs, &opts, true)
+        }
+        Command::Verify { days, record } => verify(&days, record),
+        Command::Bench { days, opts, json } => run_bench(&days, &opts, json),
+        Command::Fetch { days, base_url } => fetch(&days, base_url.as_deref()),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        } => submit(day, part, answer, base_url.as_deref()),
+        Command::New { day } => new_day(day),
+        Command::Watch {
+            day,
+            input,
+            interval,
+        } => {
+            let mut watcher = Watcher::for_day(day, input);
+            loop {
+                if let Some(answers) = watcher.poll() {
+                    println!("{answers}");
+                }
+                thread::sleep(interval);
+            }
+        }
+        Command::Help => {
+            println!("{USAGE}");
+            true
+        }
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Solves `jobs` and prints the answers, each day under a heading if
+/// `headings` is set, or the records in `opts.format`. Errors are reported on
+/// standard error, or in the records; returns whether there were none.
+fn run(jobs: &[Job], opts: &RunOptions, headings: bool) -> bool {
+    let mut ok = true;
+    let mut next = jobs.iter();
+
+    if let Some(header) = opts.format.and_then(report::header) {
+        println!("{header}");
+    }
+
+    runner::run(jobs, opts.threads, |outcome| {
+        let job = next.next().expect("an outcome for every job");
+        ok &= outcome.parts.as_ref().is_ok_and(|parts| {
+            parts
+                .iter()
+                .all(|p| matches!(p.answer, Ok(_) | Err(Error::Unsolved)))
+        });
+
+        if let Some(format) = opts.format {
+            print!("{}", report::to_lines(format, &outcome, job.parts));
+        } else {
+            print_answers(outcome, headings);
+        }
+    });
+
+    ok
+}
+
+fn print_answers(outcome: runner::Outcome, heading: bool) {
+    let n = outcome.day;
+    if heading {
+        println!("day {n}");
+    }
+
+    let parts = match outcome.parts {
+        Ok(parts) => parts,
+        Err(e) => return eprintln!("error: day {n}: {e}"),
+    };
+
+    for p in parts {
+        match p.answer {
+            Ok(answer) => println!("part {}: {answer}", p.part),
+            Err(Error::Unsolved) => println!("part {}: not solved yet", p.part),
+            Err(e) => eprintln!("error: day {n} part {}: {e}", p.part),
+        }
+    }
+}
+
+/// Prints the trace of the day of `job`, followed by its answers. Returns
+/// whether there were no errors.
+fn run_trace(job: &Job) -> bool {
+    let n = job.day.number;
+    let parsed = input::resolve(n, job.input).and_then(|p| {
+        read_input(&p)
+            .and_then(|i| job.day.parse_with(&i, job.params))
+            .map_err(|e| e.in_file(&p))
+    });
+    let parsed = match parsed {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("error: day {n}: {e}");
+            return false;
+        }
+    };
+
+    match job.day.trace(&parsed) {
+        Some(trace) => print!("{trace}"),
+        None => {
+            eprintln!("error: day {n} has no trace");
+            return false;
+        }
+    }
+
+    let mut ok = true;
+    for &p in job.parts {
+        match job.day.solve(p, &parsed) {
+            Ok(answer) => println!("part {p}: {answer}"),
+            Err(Error::Unsolved) => println!("part {p}: not solved yet"),
+            Err(e) => {
+                eprintln!("error: day {n} part {p}: {e}");
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+/// Checks the answers of every part of `days` against the answers file and
+/// prints a verdict for each. With `record`, answers not in the file yet are
+/// added to it. Returns whether all known answers matched.
+fn verify(days: &[&Day], record: bool) -> bool {
+    let path = answers::default_path();
+    let mut known = match Answers::load(&path) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+    let mut ok = true;
+    let mut recorded = false;
+
+    for day in days {
+        let n = day.number;
+        let parsed = input::resolve(n, None).and_then(|p| {
+            read_input(&p)
+                .and_then(|i| Ok((input_hash(&i), day.parse(&i)?)))
+                .map_err(|e| e.in_file(&p))
+        });
+        let (hash, i) = match parsed {
+            Ok(p) => p,
+            Err(Error::InputNotFound { .. }) => {
+                println!("day {n}: UNKNOWN (no input)");
+                continue;
+            }
+            Err(e) => {
+                eprintln!("error: day {n}: {e}");
+                ok = false;
+                continue;
+            }
+        };
+
+        for p in [1, 2] {
+            let answer = match day.solve(p, &i) {
+                Ok(a) => a,
+                Err(Error::Unsolved) => {
+                    println!("day {n} part {p}: UNKNOWN (not solved yet)");
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("error: day {n} part {p}: {e}");
+                    ok = false;
+                    continue;
+                }
+            };
+
+            match known.check(n, p, hash, &answer) {
+                Verdict::Pass => println!("day {n} part {p}: PASS"),
+                Verdict::Fail { expected } => {
+                    println!("day {n} part {p}: FAIL (expected {expected}, got {answer})");
+                    ok = false;
+                }
+                Verdict::Unknown if record => {
+                    println!("day {n} part {p}: UNKNOWN (recorded {answer})");
+                    known.insert(n, p, hash, answer);
+                    recorded = true;
+                }
+                Verdict::Unknown => println!("day {n} part {p}: UNKNOWN ({answer})"),
+            }
+        }
+    }
+
+    if recorded && let Err(e) = known.save(&path) {
+        eprintln!("error: {e}");
+        ok = false;
+    }
+
+    ok
+}
+
+/// Benchmarks `days` and prints the timings. Days without an input are
+/// skipped; returns whether there were no other errors.
+fn run_bench(days: &[&Day], opts: &bench::Options, json: bool) -> bool {
+    let mut ok = true;
+    let mut reports = vec![];
+
+    for day in days {
+        let report = input::resolve(day.number, None).and_then(|p| {
+            read_input(&p)
+                .and_then(|i| bench::bench(day, &i, opts))
+                .map_err(|e| e.in_file(&p))
+        });
+
+        match report {
+            Ok(r) => reports.push(r),
+            Err(Error::InputNotFound { .. }) => eprintln!("day {}: no input", day.number),
+            Err(e) => {
+                eprintln!("error: day {}: {e}", day.number);
+                ok = false;
+            }
+        }
+    }
+
+    if json {
+        println!("{}", bench::to_json(&reports));
+    } else {
+        print!("{}", bench::to_text(&reports));
+    }
+
+    ok
+}
+
+/// Downloads the inputs of `days` that are missing. Returns whether all of
+/// them are there now.
+fn fetch(days: &[u8], base_url: Option<&str>) -> bool {
+    let site = match site(base_url) {
+        Ok(site) => site,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+    let mut ok = true;
+
+    for &day in days {
+        match site.fetch(day) {
+            Ok(Fetched::Cached(p)) => println!("day {day}: already at {}", p.display()),
+            Ok(Fetched::Downloaded(p)) => println!("day {day}: saved to {}", p.display()),
+            Err(e) => {
+                eprintln!("error: day {day}: {e}");
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+/// Submits `answer`, or the computed answer, to `part` of `day`, unless the
+/// guesses so far rule it out. Returns whether it was correct.
+fn submit(day: u8, part: u8, answer: Option<String>, base_url: Option<&str>) -> bool {
+    let answer = match answer.map_or_else(|| solve(day, part), Ok) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("error: day {day} part {part}: {e}");
+            return false;
+        }
+    };
+
+    let path = guesses::default_path();
+    let mut guesses = match Guesses::load(&path) {
+        Ok(g) => g,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+    if let Err(r) = guesses.check(day, part, &answer) {
+        println!("day {day} part {part}: not submitting {answer}: {r}");
+        return false;
+    }
+
+    let feedback = match site(base_url).and_then(|s| s.submit(day, part, &answer)) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("error: day {day} part {part}: {e}");
+            return false;
+        }
+    };
+    let verdict = match feedback {
+        Feedback::Correct => "correct",
+        Feedback::TooHigh => "too high",
+        Feedback::TooLow => "too low",
+        Feedback::Wrong => "wrong",
+    };
+    println!("day {day} part {part}: {answer} is {verdict}");
+
+    guesses.insert(day, part, answer, feedback);
+    if let Err(e) = guesses.save(&path) {
+        eprintln!("error: {e}");
+        return false;
+    }
+
+    match guesses.bounds(day, part) {
+        _ if feedback == Feedback::Correct => {}
+        (None, None) => {}
+        (low, high) => println!(
+            "the answer is between {} and {}",
+            low.map_or("?".to_owned(), |n| n.to_string()),
+            high.map_or("?".to_owned(), |n| n.to_string())
+        ),
+    }
+
+    feedback == Feedback::Correct
+}
+
+/// Solves `part` of `day` with the default input.
+fn solve(day: u8, part: u8) -> aoc_2025::Result<String> {
+    let d = days::get(day).ok_or(Error::Invalid(format!(
+        "day {day} is not implemented, give the answer to submit"
+    )))?;
+    let path = input::resolve(day, None)?;
+    let parsed = read_input(&path)
+        .and_then(|i| d.parse(&i))
+        .map_err(|e| e.in_file(&path))?;
+
+    d.solve(part, &parsed)
+}
+
+/// Creates the skeleton of `day` in the repository. Returns whether it was
+/// created.
+fn new_day(day: u8) -> bool {
+    match scaffold::create(Path::new(env!("CARGO_MANIFEST_DIR")), day) {
+        Ok(paths) => {
+            for p in paths {
+                println!("wrote {}", p.display());
+            }
+            true
+        }
+        Err(e) => {
+            eprintln!("error: day {day}: {e}");
+            false
+        }
+    }
+}
+
+/// The website at `base_url`, if given, or else as configured by the
+/// environment.
+fn site(base_url: Option<&str>) -> aoc_2025::Result<Site> {
+    let site = Site::from_env()?;
+    Ok(match base_url {
+        Some(url) => Site::new(url, site.session),
+        None => site,
+    })
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("verify") => {
+            args.next();
+            parse_verify_args(args)
+        }
+        Some("bench") => {
+            args.next();
+            parse_bench_args(args)
+        }
+        Some("fetch") => {
+            args.next();
+            parse_fetch_args(args)
+        }
+        Some("submit") => {
+            args.next();
+            parse_submit_args(args)
+        }
+        Some("watch") => {
+            args.next();
+            parse_watch_args(args)
+        }
+        Some("new") => {
+            args.next();
+            match args.next().as_deref() {
+                None => Err("new needs a day".to_owned()),
+                Some("-h" | "--help") => Ok(Command::Help),
+                Some(d) => match args.next() {
+                    Some(a) => Err(format!("unexpected argument `{a}`")),
+                    None => Ok(Command::New {
+                        day: parse_day_number(d)?,
+                    }),
+                },
+            }
+        }
+        _ => parse_run_args(args),
+    }
+}
+
+fn parse_verify_args(args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut record = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            a => days.push(parse_day(a)?),
+        }
+    }
+
+    Ok(Command::Verify {
+        days: or_all(days),
+        record,
+    })
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut opts = bench::Options::default();
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--warmup" => opts.warmup = parse_count(&arg, args.next())?,
+            "--runs" => opts.runs = parse_count(&arg, args.next())?,
+            "--json" => json = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            a => days.push(parse_day(a)?),
+        }
+    }
+
+    Ok(Command::Bench {
+        days: or_all(days),
+        opts,
+        json,
+    })
+}
+
+fn parse_fetch_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut base_url = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => base_url = Some(args.next().ok_or("--base-url needs a value")?),
+            "-h" | "--help" => return Ok(Command::Help),
+            // Inputs can be fetched before the day is implemented.
+            a => days.push(parse_day_number(a)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = DAYS.iter().map(|d| d.number).collect();
+    }
+
+    Ok(Command::Fetch { days, base_url })
+}
+
+fn parse_submit_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut positional = vec![];
+    let mut base_url = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => base_url = Some(args.next().ok_or("--base-url needs a value")?),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional.as_slice() {
+        [day, part, answer @ ..] if answer.len() <= 1 => Ok(Command::Submit {
+            day: parse_day_number(day)?,
+            part: parse_part(part)?,
+            answer: answer.first().cloned(),
+            base_url,
+        }),
+        [_, _, _, a, ..] => Err(format!("unexpected argument `{a}`")),
+        _ => Err("submit needs a day and a part".to_owned()),
+    }
+}
+
+fn parse_watch_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut interval = Duration::from_millis(500);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => match parse_count(&arg, args.next())? {
+                0 => return Err(format!("{arg} must be at least 1")),
+                ms => interval = Duration::from_millis(ms.into()),
+            },
+            "-h" | "--help" => return Ok(Command::Help),
+            "-" => return Err("standard input cannot be watched".to_owned()),
+            a if day.is_none() => day = Some(parse_day(a)?),
+            a if input.is_none() => input = Some(PathBuf::from(a)),
+            a => return Err(format!("unexpected argument `{a}`")),
+        }
+    }
+
+    Ok(Command::Watch {
+        day: day.ok_or("no day given")?,
+        input,
+        interval,
+    })
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut all = false;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut params = Params::default();
+    let mut trace = false;
+    let mut opts = RunOptions {
+        threads: runner::default_threads(),
+        format: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-p" | "--part" => {
+                part = Some(parse_part(&args.next().ok_or("--part needs a value")?)?);
+            }
+            "--param" => params.set(&args.next().ok_or("--param needs a value")?)?,
+            "--trace" => trace = true,
+            "-j" | "--jobs" => match parse_count(&arg, args.next())? {
+                0 => return Err(format!("{arg} needs at least one thread")),
+                n => opts.threads = n as usize,
+            },
+            "-f" | "--format" => {
+                let f = args.next().ok_or("--format needs a value")?;
+                opts.format = match f.as_str() {
+                    "text" => None,
+                    f => Some(f.parse()?),
+                };
+            }
+            a if day.is_none() && !all => day = Some(parse_day(a)?),
+            a if input.is_none() && !all => input = Some(PathBuf::from(a)),
+            a => return Err(format!("unexpected argument `{a}`")),
+        }
+    }
+
+    if all {
+        if day.is_some() || part.is_some() || !params.is_empty() || trace {
+            return Err(
+                "--all cannot be combined with a day, part, parameters or --trace".to_owned(),
+            );
+        }
+        return Ok(Command::All { opts });
+    }
+    if trace && opts.format.is_some() {
+        return Err("--trace cannot be combined with --format".to_owned());
+    }
+
+    Ok(Command::Day {
+        day: day.ok_or("no day given")?,
+        part,
+        input,
+        params,
+        opts,
+        trace,
+    })
+}
+
+fn parse_count(flag: &str, value: Option<String>) -> Result<u32, String> {
+    let v = value.ok_or(format!("{flag} needs a value"))?;
+    v.parse()
+        .map_err(|_| format!("invalid count `{v}` for {flag}"))
+}
+
+/// `days`, or every day if none were given.
+fn or_all(days: Vec<&'static Day>) -> Vec<&'static Day> {
+    if days.is_empty() {
+        DAYS.iter().collect()
+    } else {
+        days
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part `{s}`")),
+    }
+}
+
+/// A day of the calendar, which need not be implemented.
+fn parse_day_number(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(n @ 1..=25) => Ok(n),
+        _ => Err(format!("invalid day `{s}`")),
+    }
+}
+
+fn parse_day(s: &str) -> Result<&'static Day, String> {
+    let n = s.parse().map_err(|_| format!("invalid day `{s}`"))?;
+    days::get(n).ok_or(format!("day {n} is not implemented"))
+}
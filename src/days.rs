@@ -0,0 +1,125 @@
+//! Every implemented day, registered for the runner.
+
+use std::any::Any;
+
+use crate::{Params, Result, Solution};
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+/// Parsed input of a [`Day`], with its concrete type erased.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+type ParseFn = fn(&str, &Params) -> Result<Parsed>;
+type PartFn = fn(&dyn Any) -> Result<String>;
+type TraceFn = fn(&dyn Any) -> Option<String>;
+
+/// A type-erased [`Solution`], so that days can be handled uniformly.
+pub struct Day {
+    pub number: u8,
+    parse: ParseFn,
+    parts: [PartFn; 2],
+    trace: TraceFn,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Self
+    where
+        S::Input: Send + Sync + 'static,
+    {
+        Day {
+            number,
+            parse: parse::<S>,
+            parts: [part_1::<S>, part_2::<S>],
+            trace: trace::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        self.parse_with(input, &Params::default())
+    }
+
+    /// Parses `input` and applies `params` to it, see
+    /// [`Solution::configure`].
+    pub fn parse_with(&self, input: &str, params: &Params) -> Result<Parsed> {
+        (self.parse)(input, params)
+    }
+
+    /// Solves `part` on input previously parsed by this day.
+    ///
+    /// # Panics
+    ///
+    /// If `part` is neither 1 nor 2.
+    pub fn solve(&self, part: u8, input: &Parsed) -> Result<String> {
+        assert!(matches!(part, 1 | 2), "there is no part {part}");
+        (self.parts[usize::from(part) - 1])(input.as_ref())
+    }
+
+    /// The trace of input previously parsed by this day, see
+    /// [`Solution::trace`].
+    pub fn trace(&self, input: &Parsed) -> Option<String> {
+        (self.trace)(input.as_ref())
+    }
+}
+
+fn parse<S: Solution>(input: &str, params: &Params) -> Result<Parsed>
+where
+    S::Input: Send + Sync + 'static,
+{
+    let mut parsed = S::parse(input)?;
+    S::configure(&mut parsed, params)?;
+    Ok(Box::new(parsed))
+}
+
+fn part_1<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Input: Send + Sync + 'static,
+{
+    Ok(S::part_1(downcast::<S>(input))?.to_string())
+}
+
+fn part_2<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Input: Send + Sync + 'static,
+{
+    Ok(S::part_2(downcast::<S>(input))?.to_string())
+}
+
+fn trace<S: Solution>(input: &dyn Any) -> Option<String>
+where
+    S::Input: Send + Sync + 'static,
+{
+    S::trace(downcast::<S>(input))
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: Send + Sync + 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different day")
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+    Day::new::<day7::Day7>(7),
+    Day::new::<day8::Day8>(8),
+    Day::new::<day9::Day9>(9),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,615 @@
+use std::fmt::{self, Display, Write};
+
+use crate::{
+    Error, Params, Solution,
+    parse::{lines, uint},
+};
+use winnow::{
+    Parser,
+    combinator::{alt, opt, terminated},
+    error::{StrContext, StrContextValue},
+    token::take_while,
+};
+
+pub struct Day1;
+
+/// The rotations, and the lock they turn.
+pub struct Safe {
+    rotations: Vec<Rotation>,
+    /// The dial every wheel starts out as.
+    dial: Dial,
+    /// The number of wheels, at least one more than the highest one turned.
+    wheels: usize,
+    /// Whether the trace draws the dial after every step.
+    face: bool,
+}
+
+/// A line of the input: `L<n>` or `R<n>` turns wheel 0, `<w>:L<n>` turns
+/// wheel `w` alone and `<w>+:L<n>` turns it with carry into the wheels after
+/// it, like an odometer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rotation {
+    wheel: usize,
+    clicks: i32,
+    carry: bool,
+}
+
+impl Solution for Day1 {
+    type Input = Safe;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let rotations = parse.parse(input)?;
+        let wheels = rotations.iter().map(|r| r.wheel + 1).max().unwrap_or(1);
+
+        Ok(Safe {
+            rotations,
+            dial: Dial::default(),
+            wheels,
+            face: false,
+        })
+    }
+
+    /// Every wheel has `size` positions, 100 by default, and starts at
+    /// `start`, 50 by default. There are at least `wheels` wheels, for the
+    /// carry to go into. With `face=true`, the trace draws the dials.
+    fn configure(input: &mut Self::Input, params: &Params) -> Result<(), Error> {
+        params.only(&["size", "start", "wheels", "face"])?;
+        let wheels = params.get::<usize>("wheels")?.unwrap_or(0);
+        let size = params.get("size")?.unwrap_or(input.dial.size);
+        let start = params.get("start")?.unwrap_or(input.dial.position);
+
+        let invalid = |name: &str, message: String| Error::Param {
+            name: name.to_owned(),
+            message,
+        };
+        if size == 0 {
+            return Err(invalid("size", "a dial needs a position".to_owned()));
+        }
+        if start >= size {
+            return Err(invalid(
+                "start",
+                format!("{start} is not on a dial of size {size}"),
+            ));
+        }
+
+        if wheels > 256 {
+            return Err(invalid("wheels", "at most 256 wheels fit".to_owned()));
+        }
+
+        input.dial = Dial::new(size, start);
+        input.wheels = input.wheels.max(wheels);
+        input.face = params.get("face")?.unwrap_or(input.face);
+        Ok(())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(per_wheel(zero_hits(input).iter().map(|h| h.stops)))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(per_wheel(zero_hits(input).iter().map(|h| h.passes)))
+    }
+
+    fn trace(input: &Self::Input) -> Option<String> {
+        Some(trace(input))
+    }
+}
+
+/// A dial with the positions `0..size`, which increase to the right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(100, 50)
+    }
+}
+
+impl Dial {
+    /// # Panics
+    ///
+    /// If `start` is not less than `size`.
+    pub fn new(size: u64, start: u64) -> Self {
+        assert!(start < size, "{start} is not on a dial of size {size}");
+        Dial {
+            size,
+            position: start,
+        }
+    }
+
+    /// Turns the dial by `clicks`, to the right if positive and to the left
+    /// if negative. Returns the new position and how many times the dial
+    /// pointed at 0 along the way, including at the end.
+    pub fn rotate(&mut self, clicks: i64) -> (u64, u64) {
+        let size = i128::from(self.size);
+        let position = i128::from(self.position);
+        let clicks = i128::from(clicks);
+
+        // The first zero in the direction of the rotation is this many clicks
+        // away, a full turn when starting at zero, and every `size` clicks
+        // after that is another one.
+        let to_zero = if clicks < 0 {
+            position
+        } else {
+            size - position
+        };
+        let to_zero = if to_zero == 0 { size } else { to_zero };
+        let zeros = if clicks.abs() >= to_zero {
+            (clicks.abs() - to_zero) / size + 1
+        } else {
+            0
+        };
+
+        self.position = (position + clicks).rem_euclid(size) as u64;
+        (self.position, zeros as u64)
+    }
+
+    /// How many times turning by `clicks` wraps around from the last position
+    /// to 0, or from 0 to the last position if negative.
+    fn wraps(&self, clicks: i64) -> i64 {
+        (i128::from(self.position) + i128::from(clicks)).div_euclid(i128::from(self.size)) as i64
+    }
+}
+
+/// How often a wheel pointed at 0.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Hits {
+    /// Rotations of the wheel, not counting carries that did not move it,
+    /// that left it at 0.
+    stops: u64,
+    /// Clicks that moved the wheel onto 0.
+    passes: u64,
+}
+
+/// A wheel turning, as part of a rotation.
+struct Step {
+    /// The index of the rotation.
+    rotation: usize,
+    wheel: usize,
+    clicks: i64,
+    from: u64,
+    to: u64,
+    /// How many times the wheel pointed at 0, including at the end.
+    passes: u64,
+}
+
+/// Turns the wheels of `safe` through all of its rotations and passes every
+/// step to `step`, in order. A rotation with carry takes a step for every
+/// wheel it moves.
+fn turn(safe: &Safe, mut step: impl FnMut(&Step)) {
+    let mut wheels = vec![safe.dial; safe.wheels];
+
+    for (i, r) in safe.rotations.iter().enumerate() {
+        let mut clicks = i64::from(r.clicks);
+        for (w, wheel) in wheels.iter_mut().enumerate().skip(r.wheel) {
+            let from = wheel.position;
+            let carry = wheel.wraps(clicks);
+            let (to, passes) = wheel.rotate(clicks);
+            step(&Step {
+                rotation: i,
+                wheel: w,
+                clicks,
+                from,
+                to,
+                passes,
+            });
+
+            clicks = carry;
+            if !r.carry || clicks == 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// The zero hits of every wheel after all rotations of `safe`.
+fn zero_hits(safe: &Safe) -> Vec<Hits> {
+    let mut hits = vec![Hits::default(); safe.wheels];
+    turn(safe, |s| {
+        hits[s.wheel].passes += s.passes;
+        hits[s.wheel].stops += u64::from(s.to == 0);
+    });
+
+    hits
+}
+
+/// A line for every step of `safe`, with the zeros it counts: landing on 0
+/// counts for both parts and passing it for part 2 only, while staying at 0
+/// without moving counts as a stop for part 1 only.
+fn trace(safe: &Safe) -> String {
+    let mut out = String::new();
+    turn(safe, |s| {
+        let r = &safe.rotations[s.rotation];
+        if s.wheel == r.wheel {
+            let _ = write!(out, "{r}: ");
+        } else {
+            let _ = write!(out, "  carry {:+} into wheel {}: ", s.clicks, s.wheel);
+        }
+        let _ = write!(out, "{} -> {}", s.from, s.to);
+
+        let landing = u64::from(s.to == 0 && s.passes > 0);
+        if landing > 0 {
+            out.push_str(", landing +1");
+        } else if s.to == 0 {
+            out.push_str(", stop +1");
+        }
+        if s.passes > landing {
+            let _ = write!(out, ", passing +{}", s.passes - landing);
+        }
+        out.push('\n');
+
+        if safe.face {
+            out.push_str(&face(Dial::new(safe.dial.size, s.to)));
+        }
+    });
+
+    out
+}
+
+/// Draws `dial` as a ring of at most 16 marks, with 0 at the top, the
+/// positions increasing clockwise and `*` on the mark of the pointer. Each
+/// mark stands for an equal share of the positions.
+fn face(dial: Dial) -> String {
+    const RADIUS: usize = 4;
+    let marks = dial.size.min(16);
+    let mut rows = vec![vec![b' '; 4 * RADIUS + 1]; 2 * RADIUS + 1];
+
+    // Characters are about twice as high as they are wide.
+    let pointer = u128::from(dial.position) * u128::from(marks) / u128::from(dial.size);
+    for k in 0..marks {
+        let angle = std::f64::consts::TAU * k as f64 / marks as f64;
+        let x = RADIUS as f64 * (2.0 + 2.0 * angle.sin());
+        let y = RADIUS as f64 * (1.0 - angle.cos());
+        rows[y.round() as usize][x.round() as usize] = match k {
+            _ if u128::from(k) == pointer => b'*',
+            0 => b'0',
+            _ => b'.',
+        };
+    }
+
+    let label = dial.position.to_string();
+    let middle = &mut rows[RADIUS];
+    if label.len() < middle.len() - 4 {
+        let at = (middle.len() - label.len()) / 2;
+        middle[at..at + label.len()].copy_from_slice(label.as_bytes());
+    }
+
+    rows.iter()
+        .map(|r| {
+            format!("    {}", String::from_utf8_lossy(r))
+                .trim_end()
+                .to_owned()
+                + "\n"
+        })
+        .collect()
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.wheel != 0 || self.carry {
+            let carry = if self.carry { "+" } else { "" };
+            write!(f, "{}{carry}:", self.wheel)?;
+        }
+        let direction = if self.clicks < 0 { 'L' } else { 'R' };
+        write!(f, "{direction}{}", self.clicks.unsigned_abs())
+    }
+}
+
+/// The total of `counts`, followed by each of them if there is more than one
+/// wheel.
+fn per_wheel(counts: impl ExactSizeIterator<Item = u64>) -> String {
+    if counts.len() == 1 {
+        return counts.sum::<u64>().to_string();
+    }
+
+    let counts = counts.collect::<Vec<_>>();
+    let wheels = counts
+        .iter()
+        .enumerate()
+        .map(|(w, c)| format!("wheel {w}: {c}"))
+        .collect::<Vec<_>>();
+    format!("{} ({})", counts.iter().sum::<u64>(), wheels.join(", "))
+}
+
+fn parse(input: &mut &str) -> winnow::Result<Vec<Rotation>> {
+    lines(parse_line).parse_next(input)
+}
+
+fn parse_line(s: &mut &str) -> winnow::Result<Rotation> {
+    let wheel = opt(terminated((uint::<u8>, opt('+')), ':')).parse_next(s)?;
+    let (wheel, carry) = wheel.map_or((0, false), |(w, c)| (w.into(), c.is_some()));
+
+    Ok(Rotation {
+        wheel,
+        clicks: parse_clicks(s)?,
+        carry,
+    })
+}
+
+fn parse_clicks(s: &mut &str) -> winnow::Result<i32> {
+    let sign = alt(('L'.value(-1), 'R'.value(1)))
+        .context(StrContext::Expected(StrContextValue::CharLiteral('L')))
+        .context(StrContext::Expected(StrContextValue::CharLiteral('R')))
+        .parse_next(s)?;
+    let num = take_while(1.., '0'..='9')
+        .try_map(str::parse::<i32>)
+        .context(StrContext::Expected(StrContextValue::Description("number")))
+        .parse_next(s)?;
+
+    Ok(sign * num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    use winnow::Parser;
+
+    const INPUT: &str = include_str!("../../examples/day1/example.in");
+
+    /// Turns a single wheel of the usual dial by `rotations`.
+    fn safe(rotations: &[i32]) -> Safe {
+        Safe {
+            rotations: rotations
+                .iter()
+                .map(|&clicks| Rotation {
+                    wheel: 0,
+                    clicks,
+                    carry: false,
+                })
+                .collect(),
+            dial: Dial::default(),
+            wheels: 1,
+            face: false,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let rotations = parse.parse(INPUT).unwrap();
+
+        assert_eq!(
+            rotations.iter().map(|r| r.clicks).collect::<Vec<_>>(),
+            vec![-68, -30, 48, -5, 60, -55, -1, -99, 14, -82]
+        );
+        assert!(rotations.iter().all(|r| r.wheel == 0 && !r.carry));
+    }
+
+    #[test]
+    fn test_parse_wheels() {
+        let rotation = |wheel, clicks, carry| Rotation {
+            wheel,
+            clicks,
+            carry,
+        };
+
+        assert_eq!(
+            parse.parse("L5\n2:R3\n1+:L4\n0:R0\n").unwrap(),
+            vec![
+                rotation(0, -5, false),
+                rotation(2, 3, false),
+                rotation(1, -4, true),
+                rotation(0, 0, false),
+            ]
+        );
+        assert_eq!(Day1::parse("3:L1\n").unwrap().wheels, 4);
+        for invalid in ["256:R1\n", ":R1\n", "1-:R1\n", "1R1\n", "1:\n"] {
+            assert!(parse.parse(invalid).is_err(), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse.parse("L68\nX30\n").is_err());
+        assert!(parse.parse("R99999999999\n").is_err());
+    }
+
+    /// The dial moved one click at a time, as part 2 used to be solved.
+    fn part_2_clicks(input: &[i32]) -> u64 {
+        let mut cur = 50i32;
+        let mut count = 0;
+
+        for mut i in input.iter().cloned() {
+            while i < 0 {
+                i += 1;
+                cur += 1;
+                if cur == 100 {
+                    cur = 0
+                }
+                if cur == 0 {
+                    count += 1
+                }
+            }
+
+            while i > 0 {
+                i -= 1;
+                cur -= 1;
+                if cur == -1 {
+                    cur = 99
+                }
+                if cur == 0 {
+                    count += 1
+                }
+            }
+        }
+
+        count
+    }
+
+    #[test]
+    fn test_dial() {
+        let mut dial = Dial::new(10, 3);
+        assert_eq!(dial.rotate(-3), (0, 1));
+        assert_eq!(dial.rotate(-10), (0, 1));
+        assert_eq!(dial.rotate(25), (5, 2));
+        assert_eq!(dial.rotate(-4), (1, 0));
+        assert_eq!(dial.rotate(0), (1, 0));
+        assert_eq!(Dial::new(1, 0).rotate(-7), (0, 7));
+    }
+
+    #[test]
+    fn test_configure() {
+        let mut safe = Day1::parse(INPUT).unwrap();
+        let mut params = Params::default();
+        params.set("size=10").unwrap();
+        params.set("start=2").unwrap();
+        Day1::configure(&mut safe, &params).unwrap();
+        assert_eq!(safe.dial, Dial::new(10, 2));
+
+        params.set("start=10").unwrap();
+        assert_eq!(
+            Day1::configure(&mut safe, &params).unwrap_err().to_string(),
+            "parameter `start`: 10 is not on a dial of size 10"
+        );
+        params.set("notches=3").unwrap();
+        assert!(Day1::configure(&mut safe, &params).is_err());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let part_2 = |rotations: &[i32]| zero_hits(&safe(rotations))[0].passes;
+
+        assert_eq!(part_2(&[-68, -30, 48, -5, 60, -55, -1, -99, 14, -82]), 6);
+        assert_eq!(part_2(&[-50, 100, -100, 1, -1]), 4);
+        assert_eq!(part_2(&[1_000_000_000, -1_000_000_000]), 20_000_000);
+        assert_eq!(part_2(&[i32::MIN, i32::MAX]), 42_949_672);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut safe = Day1::parse("L68\nL30\nR48\nL5\nR260\n").unwrap();
+
+        assert_eq!(
+            Day1::trace(&safe).unwrap(),
+            "L68: 50 -> 82, passing +1\n\
+             L30: 82 -> 52\n\
+             R48: 52 -> 0, landing +1\n\
+             L5: 0 -> 95\n\
+             R260: 95 -> 55, passing +3\n"
+        );
+
+        // Not moving off 0 is a stop for part 1, but no zero for part 2.
+        let stop = Day1::parse("L50\nR0\nR100\n").unwrap();
+        assert_eq!(
+            trace(&stop),
+            "L50: 50 -> 0, landing +1\n\
+             R0: 0 -> 0, stop +1\n\
+             R100: 0 -> 0, landing +1\n"
+        );
+        assert_eq!(Day1::part_1(&stop).unwrap().to_string(), "3");
+        assert_eq!(Day1::part_2(&stop).unwrap().to_string(), "2");
+
+        let mut params = Params::default();
+        params.set("face=true").unwrap();
+        Day1::configure(&mut safe, &params).unwrap();
+        safe.rotations.truncate(1);
+        assert_eq!(
+            trace(&safe),
+            "L68: 50 -> 82, passing +1\n\
+             \x20        .  0  .\n\
+             \x20     .           .\n\
+             \x20    *             .\n\
+             \n\
+             \x20   .      82       .\n\
+             \n\
+             \x20    .             .\n\
+             \x20     .           .\n\
+             \x20        .  .  .\n"
+        );
+
+        // Steps that carry into the next wheel are traced separately.
+        let safe = Day1::parse("R0\n0+:L51\n1:R1\n").unwrap();
+        assert_eq!(
+            trace(&safe),
+            "R0: 50 -> 50\n\
+             0+:L51: 50 -> 99, passing +1\n\
+             \x20 carry -1 into wheel 1: 50 -> 49\n\
+             1:R1: 49 -> 50\n"
+        );
+    }
+
+    #[test]
+    fn test_face() {
+        assert_eq!(
+            face(Dial::new(4, 1)),
+            "            0\n\n\n\n    .       1       *\n\n\n\n            .\n"
+        );
+        // A pointer between marks is on the mark before it.
+        assert!(face(Dial::new(1000, 999)).starts_with("         *  0  .\n"));
+    }
+
+    #[test]
+    fn test_wheels() {
+        let mut safe = Day1::parse("0+:R25\n1:L2\n0+:L6\n").unwrap();
+        let mut params = Params::default();
+        params.set("size=10").unwrap();
+        params.set("start=0").unwrap();
+        Day1::configure(&mut safe, &params).unwrap();
+
+        assert_eq!(
+            Day1::part_1(&safe).unwrap().to_string(),
+            "1 (wheel 0: 0, wheel 1: 1)"
+        );
+        assert_eq!(
+            Day1::part_2(&safe).unwrap().to_string(),
+            "4 (wheel 0: 3, wheel 1: 1)"
+        );
+
+        params.set("wheels=3").unwrap();
+        Day1::configure(&mut safe, &params).unwrap();
+        assert_eq!(
+            Day1::part_2(&safe).unwrap().to_string(),
+            "4 (wheel 0: 3, wheel 1: 1, wheel 2: 0)"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_part_2_prop(input in vec(-350..350i32, 0..50)) {
+            prop_assert_eq!(zero_hits(&safe(&input))[0].passes, part_2_clicks(&input));
+        }
+
+        #[test]
+        fn test_odometer_prop(clicks in vec(-2000..2000i32, 0..20)) {
+            // Three wheels of 10 with carry count from 000 to 999.
+            let mut s = safe(&clicks);
+            s.rotations.iter_mut().for_each(|r| r.carry = true);
+            s.dial = Dial::new(10, 0);
+            s.wheels = 3;
+
+            let mut wheels = [s.dial; 3];
+            for r in &s.rotations {
+                let mut c = i64::from(r.clicks);
+                for w in &mut wheels {
+                    let carry = w.wraps(c);
+                    w.rotate(c);
+                    c = carry;
+                }
+            }
+            let value = wheels.iter().rev().fold(0, |v, w| v * 10 + w.position as i64);
+            let total = clicks.iter().map(|&c| i64::from(c)).sum::<i64>();
+
+            prop_assert_eq!(value, total.rem_euclid(1000));
+            prop_assert_eq!(zero_hits(&s).len(), 3);
+        }
+
+        #[test]
+        fn test_rotate_prop(
+            (size, start) in (1..20u64).prop_flat_map(|size| (Just(size), 0..size)),
+            clicks in -100..100i64,
+        ) {
+            let mut dial = Dial::new(size, start);
+            let (mut position, mut zeros) = (start, 0);
+            for _ in 0..clicks.unsigned_abs() {
+                position = (position + if clicks < 0 { size - 1 } else { 1 }) % size;
+                zeros += u64::from(position == 0);
+            }
+
+            prop_assert_eq!(dial.rotate(clicks), (position, zeros));
+            prop_assert_eq!(dial.position, position);
+        }
+    }
+}
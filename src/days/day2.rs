@@ -0,0 +1,211 @@
+use crate::{
+    Error, RangeSet, Solution,
+    parse::{range_inclusive, uint},
+};
+use winnow::{
+    self as w, Parser,
+    ascii::line_ending,
+    combinator::{opt, separated, terminated},
+};
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = RangeSet<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse.parse(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_2(input))
+    }
+}
+
+fn part_1(i: &RangeSet<u64>) -> u128 {
+    i.ranges()
+        .flat_map(|r| {
+            (2..=DIGITS)
+                .step_by(2)
+                .map(move |len| repeated_sum(&r, len, len / 2))
+        })
+        .sum()
+}
+
+fn part_2(i: &RangeSet<u64>) -> u128 {
+    i.ranges()
+        .flat_map(|r| (2..=DIGITS).map(move |len| periodic_sum(&r, len)))
+        .sum()
+}
+
+/// The most digits a `u64` has.
+const DIGITS: u32 = u64::MAX.ilog10() + 1;
+
+/// The sum of the numbers in `range` with `len` digits that are a block of
+/// `period` digits repeated, which `period` must divide.
+///
+/// These are the blocks `b` of `period` digits times `1…01…01` with a one
+/// every `period` digits, e.g. `b × 10101` for 6 digits and a period of 2,
+/// so the blocks in the range form an arithmetic series.
+fn repeated_sum(range: &RangeInclusive<u64>, len: u32, period: u32) -> u128 {
+    let ones = (0..len / period)
+        .map(|k| 10u128.pow(k * period))
+        .sum::<u128>();
+    let lo = u128::from(*range.start())
+        .div_ceil(ones)
+        .max(10u128.pow(period - 1));
+    let hi = (u128::from(*range.end()) / ones).min(10u128.pow(period) - 1);
+
+    if lo > hi {
+        return 0;
+    }
+    ones * ((lo + hi) * (hi - lo + 1) / 2)
+}
+
+/// The sum of the numbers in `range` with `len` digits that are a block
+/// repeated at least twice.
+///
+/// A number repeats a block of every multiple of its shortest period that
+/// divides `len`, e.g. 111111 repeats 1, 11 and 111. So each number is only
+/// counted for its shortest period, by taking off the numbers with a shorter
+/// period from each [`repeated_sum`].
+fn periodic_sum(range: &RangeInclusive<u64>, len: u32) -> u128 {
+    // The sum of the numbers whose shortest period is the index.
+    let mut shortest = vec![0; len as usize];
+    for period in (1..len).filter(|p| len.is_multiple_of(*p)) {
+        let shorter = (1..period)
+            .filter(|d| period.is_multiple_of(*d))
+            .map(|d| shortest[d as usize])
+            .sum::<u128>();
+        shortest[period as usize] = repeated_sum(range, len, period) - shorter;
+    }
+
+    shortest.iter().sum()
+}
+
+fn parse(s: &mut &str) -> w::Result<RangeSet<u64>> {
+    terminated(separated(1.., parse_range, ','), opt(line_ending))
+        .map(|v: Vec<_>| RangeSet::from_iter(v))
+        .parse_next(s)
+}
+
+fn parse_range(s: &mut &str) -> w::Result<RangeInclusive<u64>> {
+    range_inclusive(uint).parse_next(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use proptest::proptest;
+    use winnow::Parser;
+
+    const INPUT: &str = include_str!("../../examples/day2/example.in");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse.parse(INPUT).unwrap().ranges().collect::<Vec<_>>(),
+            vec![
+                11..=22,
+                95..=115,
+                998..=1012,
+                222220..=222224,
+                446443..=446449,
+                565653..=565659,
+                1698522..=1698528,
+                38593856..=38593862,
+                824824821..=824824827,
+                1188511880..=1188511890,
+                2121212118..=2121212124
+            ]
+        );
+    }
+
+    fn repeated_twice(n: &u64) -> bool {
+        let s = format!("{}", n);
+
+        if s.len() % 2 != 0 {
+            return false;
+        }
+
+        let mid = s.len() >> 1;
+
+        s[0..mid] == s[mid..]
+    }
+
+    fn repeated_any(n: &u64) -> bool {
+        let s = format!("{}", n);
+
+        fact(s.len()).any(|f| {
+            (s.chars().chunks(f))
+                .into_iter()
+                .map(|c| c.collect::<String>())
+                .all_equal()
+        })
+    }
+
+    fn fact(n: usize) -> impl Iterator<Item = usize> {
+        (1..n).filter(move |i| n.is_multiple_of(*i)).fuse()
+    }
+
+    #[test]
+    fn test_parts() {
+        let input = parse.parse(INPUT).unwrap();
+
+        assert_eq!(part_1(&input), 1227775554);
+        assert_eq!(part_2(&input), 4174379265);
+    }
+
+    #[test]
+    fn test_wide_ranges() {
+        let ids = |r: RangeInclusive<u64>| RangeSet::from_iter([r]);
+
+        // Every block of up to 5 digits, repeated twice.
+        assert_eq!(part_1(&ids(1..=9999999999)), 495495949990950);
+        assert_eq!(part_2(&ids(1..=9)), 0);
+        assert_eq!(
+            part_2(&ids(11..=111)),
+            11 + 22 + 33 + 44 + 55 + 66 + 77 + 88 + 99 + 111
+        );
+        assert_eq!(part_2(&ids(111111..=111111)), 111111);
+        // 1844674400 to 1844674407, repeated twice.
+        assert_eq!(
+            part_2(&ids(18446744000000000000..=u64::MAX)),
+            147573952294757395228
+        );
+        assert!(part_2(&ids(0..=u64::MAX)) > part_1(&ids(0..=u64::MAX)));
+    }
+
+    proptest! {
+    #[test]
+    fn test_parts_prop(start in 0..2_000_000u64, width in 0..5000u64) {
+        let ids = RangeSet::from_iter([start..=start + width]);
+
+        assert_eq!(
+            part_1(&ids),
+            ids.values().filter(repeated_twice).map(u128::from).sum()
+        );
+        assert_eq!(
+            part_2(&ids),
+            ids.values().filter(repeated_any).map(u128::from).sum()
+        );
+    }
+    }
+
+    #[test]
+    fn test_fact() {
+        assert_eq!(fact(1).collect::<Vec<_>>(), vec![]);
+        assert_eq!(fact(2).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(fact(3).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(fact(4).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(fact(5).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(fact(6).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+}
@@ -1,22 +1,35 @@
-use std::fs;
+use std::fmt::Display;
 
-use winnow::{
-    self as w, Parser,
-    ascii::line_ending,
-    combinator::{repeat, terminated},
-    token::one_of,
-};
+use crate::{Error, Solution, parse::lines};
+use winnow::{self as w, Parser, combinator::repeat, token::one_of};
 
-fn main() {
-    let i = fs::read_to_string("input/day3").unwrap();
-    let i = parse.parse(&i).unwrap();
+pub struct Day3;
 
-    println!("part 1: {}", joltages(&i, 2).sum::<u64>());
-    println!("part 2: {}", joltages(&i, 12).sum::<u64>());
+impl Solution for Day3 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse.parse(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(joltages(input, 2)?.sum::<u64>())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(joltages(input, 12)?.sum::<u64>())
+    }
 }
 
-fn joltages(i: &[Vec<u8>], digits: usize) -> impl Iterator<Item = u64> {
-    i.iter().map(move |v| biggest_joltage(v, digits))
+fn joltages(i: &[Vec<u8>], digits: usize) -> Result<impl Iterator<Item = u64>, Error> {
+    if let Some(n) = i.iter().position(|v| v.len() < digits) {
+        return Err(Error::Invalid(format!(
+            "bank {} has fewer than {digits} batteries",
+            n + 1
+        )));
+    }
+
+    Ok(i.iter().map(move |v| biggest_joltage(v, digits)))
 }
 
 fn biggest_joltage(v: &[u8], dig: usize) -> u64 {
@@ -36,7 +49,7 @@ fn biggest_joltage(v: &[u8], dig: usize) -> u64 {
 }
 
 fn parse(s: &mut &str) -> w::Result<Vec<Vec<u8>>> {
-    repeat(1.., terminated(parse_bank, line_ending)).parse_next(s)
+    lines(parse_bank).parse_next(s)
 }
 
 fn parse_bank(s: &mut &str) -> w::Result<Vec<u8>> {
@@ -53,12 +66,7 @@ mod tests {
     use proptest::proptest;
     use winnow::Parser;
 
-    const INPUT: &str = concat!(
-        "987654321111111\n",
-        "811111111111119\n",
-        "234234234234278\n",
-        "818181911112111\n",
-    );
+    const INPUT: &str = include_str!("../../examples/day3/example.in");
 
     #[test]
     fn test_parse() {
@@ -71,7 +79,9 @@ mod tests {
     #[test]
     fn test_part_1() {
         assert_eq!(
-            joltages(&parse.parse(INPUT).unwrap(), 2).collect::<Vec<_>>(),
+            joltages(&parse.parse(INPUT).unwrap(), 2)
+                .unwrap()
+                .collect::<Vec<_>>(),
             vec![98, 89, 78, 92]
         );
     }
@@ -79,36 +89,43 @@ mod tests {
     #[test]
     fn test_part_2() {
         assert_eq!(
-            joltages(&parse.parse(INPUT).unwrap(), 12).collect::<Vec<_>>(),
+            joltages(&parse.parse(INPUT).unwrap(), 12)
+                .unwrap()
+                .collect::<Vec<_>>(),
             vec![987654321111, 811111111119, 434234234278, 888911112111]
         );
     }
 
+    #[test]
+    fn test_short_bank() {
+        assert!(joltages(&[vec![1, 2, 3], vec![4, 5]], 3).is_err());
+    }
+
     #[test]
     fn test_joltage() {
-        assert_eq!(biggest_joltage(&vec![0, 0, 1, 1, 0], 4), 110);
-        assert_eq!(biggest_joltage(&vec![9, 9, 9, 1, 9, 1, 1], 4), 9999);
-        assert_eq!(biggest_joltage(&vec![9, 9, 9, 1, 9, 1, 1], 5), 99991);
-        assert_eq!(biggest_joltage(&vec![9, 1, 9], 2), 99);
-        assert_eq!(biggest_joltage(&vec![1, 4, 2], 2), 42);
-        assert_eq!(biggest_joltage(&vec![1, 2, 3], 2), 23);
+        assert_eq!(biggest_joltage(&[0, 0, 1, 1, 0], 4), 110);
+        assert_eq!(biggest_joltage(&[9, 9, 9, 1, 9, 1, 1], 4), 9999);
+        assert_eq!(biggest_joltage(&[9, 9, 9, 1, 9, 1, 1], 5), 99991);
+        assert_eq!(biggest_joltage(&[9, 1, 9], 2), 99);
+        assert_eq!(biggest_joltage(&[1, 4, 2], 2), 42);
+        assert_eq!(biggest_joltage(&[1, 2, 3], 2), 23);
         assert_eq!(
-            biggest_joltage(&vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 12),
+            biggest_joltage(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1], 12),
             987654321111
         );
-        assert_eq!(biggest_joltage(&vec![9, 5, 5, 2, 9], 4), 9559);
+        assert_eq!(biggest_joltage(&[9, 5, 5, 2, 9], 4), 9559);
     }
 
     proptest! {
     #[test]
     fn test_joltage_prop(s in "[1-9]{7,}") {
-        fn brute(v: &Vec<u8>) -> u64 {
+        fn brute(v: &[u8]) -> u64 {
             let l = v.len();
             let mut max = 0;
             for a in 0..l - 3 {
                 for b in a+1..l - 2 {
                     for c in b+1..l - 1 {
-                        for d in c+1..l - 0 {
+                        for d in c+1..l {
                             max = format!("{}{}{}{}", v[a], v[b], v[c], v[d])
                                 .parse::<u64>()
                                 .unwrap()
@@ -0,0 +1,103 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    Error, Solution,
+    grid::{Grid, Pos},
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_2(input))
+    }
+}
+
+fn part_1(rolls: &Grid<bool>) -> usize {
+    rolls
+        .find_all(&true)
+        .filter(|&r| adjacent(r, rolls).count() < 4)
+        .count()
+}
+
+fn part_2(rolls: &Grid<bool>) -> usize {
+    let mut rolls = rolls.clone();
+    let mut to_check: HashSet<Pos> = rolls.find_all(&true).collect();
+    let mut sum = 0;
+
+    loop {
+        let removed = to_check
+            .iter()
+            .copied()
+            .filter(|&r| adjacent(r, &rolls).count() < 4)
+            .collect::<Vec<_>>();
+
+        sum += removed.len();
+
+        if removed.is_empty() {
+            break;
+        }
+
+        removed.iter().for_each(|&r| rolls[r] = false);
+        to_check = removed.iter().flat_map(|&r| adjacent(r, &rolls)).collect();
+    }
+
+    sum
+}
+
+fn adjacent(r: Pos, all: &Grid<bool>) -> impl Iterator<Item = Pos> {
+    all.neighbors_8(r).filter(|&p| all[p])
+}
+
+fn parse_input(i: &str) -> Result<Grid<bool>, Error> {
+    Grid::parse(i, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_input(concat!("..@..@@\n", "@@..@@.\n", ".@.@.@.\n",))
+                .unwrap()
+                .find_all(&true)
+                .collect::<HashSet<_>>(),
+            HashSet::from([
+                Pos::new(2, 0),
+                Pos::new(5, 0),
+                Pos::new(6, 0),
+                Pos::new(0, 1),
+                Pos::new(1, 1),
+                Pos::new(4, 1),
+                Pos::new(5, 1),
+                Pos::new(1, 2),
+                Pos::new(3, 2),
+                Pos::new(5, 2),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            parse_input("..@\n.#.\n"),
+            Err(Error::Parse(d)) if (d.line, d.column) == (2, 2)
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use crate::{
+    Error, RangeSet, Solution,
+    parse::{lines, range_inclusive, uint},
+};
+use winnow::{Parser, Result, ascii::line_ending, combinator::seq};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input {
+    fresh_ranges: RangeSet<u64>,
+    available: Vec<u64>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse.parse(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_2(input))
+    }
+}
+
+fn part_1(i: &Input) -> usize {
+    i.available
+        .iter()
+        .filter(|&&a| i.fresh_ranges.contains(a))
+        .count()
+}
+
+fn part_2(i: &Input) -> u128 {
+    i.fresh_ranges.len()
+}
+
+fn parse(i: &mut &str) -> Result<Input> {
+    seq! {Input {
+        fresh_ranges: fresh_ranges,
+        _: line_ending,
+        available: available
+    }}
+    .parse_next(i)
+}
+
+fn fresh_ranges(i: &mut &str) -> Result<RangeSet<u64>> {
+    lines(range_inclusive(uint))
+        .map(RangeSet::from_iter)
+        .parse_next(i)
+}
+
+fn available(i: &mut &str) -> Result<Vec<u64>> {
+    lines(uint).parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winnow::Parser;
+
+    const INPUT: &str = include_str!("../../examples/day5/example.in");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse.parse(INPUT),
+            Ok(Input {
+                fresh_ranges: RangeSet::from_iter([3..=5, 10..=20]),
+                available: vec![1, 5, 8, 11, 17, 32],
+            })
+        );
+    }
+
+    #[test]
+    fn test_reduce() {
+        assert_eq!(
+            fresh_ranges.parse("154-33\n204-209\n153-90\n132-84\n10-246\n"),
+            Ok(RangeSet::from_iter([10..=246]))
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::Display;
+
+use crate::{
+    Error, Solution,
+    parse::{columns, uint},
+};
+use itertools::Itertools;
+use winnow::{
+    Parser, Result,
+    ascii::{line_ending, space0},
+    combinator::{alt, opt, peek, repeat_till, terminated},
+    error::{StrContext, StrContextValue},
+    stream::AsChar,
+    token::one_of,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Input {
+    problems: Vec<Problem>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Problem {
+    numbers: Vec<u64>,
+    op: Op,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Op {
+    Plus,
+    Times,
+}
+
+/// The worksheet read both ways: row by row for part 1 and column by column
+/// for part 2.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Worksheet {
+    rows: Input,
+    columns: Input,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Worksheet {
+            rows: parse_1(input)?,
+            columns: parse_2(input)?,
+        })
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        sum_solve(&input.rows)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        sum_solve(&input.columns)
+    }
+}
+
+fn sum_solve(i: &Input) -> Result<u64, Error> {
+    i.problems.iter().enumerate().try_fold(0u64, |sum, (n, p)| {
+        let answer = solve(p).ok_or_else(|| {
+            Error::Invalid(format!("the answer to problem {} is too large", n + 1))
+        })?;
+        sum.checked_add(answer)
+            .ok_or_else(|| Error::Invalid("the grand total is too large".to_owned()))
+    })
+}
+
+/// The answer to `p`, or `None` if it does not fit in a `u64`.
+fn solve(p: &Problem) -> Option<u64> {
+    let mut numbers = p.numbers.iter().copied();
+    match p.op {
+        Op::Plus => numbers.try_fold(0, u64::checked_add),
+        Op::Times => numbers.try_fold(1, u64::checked_mul),
+    }
+}
+
+fn parse_1(i: &str) -> Result<Input, Error> {
+    let ((ns, _), ops): ((Vec<Vec<u64>>, _), _) = (
+        repeat_till(
+            1..,
+            terminated(number_line, line_ending),
+            peek((space0, one_of(['+', '*']))),
+        ),
+        terminated(op_line, opt(line_ending)),
+    )
+        .parse(i)?;
+
+    let len = ops.len();
+    if let Some(n) = ns.iter().position(|e| e.len() != len) {
+        return Err(Error::Invalid(format!(
+            "line {} has {} numbers, but there are {len} operators",
+            n + 1,
+            ns[n].len()
+        )));
+    }
+
+    let mut r = Input {
+        problems: Vec::with_capacity(len),
+    };
+    for i in 0..len {
+        r.problems.push(Problem {
+            numbers: ns.iter().map(|v| v[i]).collect(),
+            op: ops[i],
+        });
+    }
+
+    Ok(r)
+}
+
+fn parse_2(i: &str) -> Result<Input, Error> {
+    let nlines = i.lines().count();
+    let linelen = i
+        .lines()
+        .next()
+        .ok_or_else(|| Error::Invalid("the worksheet is empty".to_owned()))?
+        .chars()
+        .count();
+
+    if !i.lines().map(|l| l.chars().count()).all_equal() {
+        return Err(Error::Invalid(
+            "the worksheet's lines differ in length".to_owned(),
+        ));
+    }
+
+    let cols = (0..linelen).map(|n| i.lines().map(move |l| l.chars().nth(n).unwrap()));
+
+    let chunks = cols.rev().chunk_by(|key| !key.clone().all(|c| c == ' '));
+    let chunks = chunks
+        .into_iter()
+        .filter_map(|(key, group)| key.then_some(group));
+
+    let problems = chunks.map(|group| {
+        let (g1, g2) = group.tee();
+
+        (
+            g1.take(nlines - 1).map(|col| {
+                col.take(nlines - 1)
+                    .filter_map(|c| c.to_digit(10).map(u64::from))
+                    .fold(0, |acc, x| acc * 10 + x)
+            }),
+            g2.map(|mut c| c.next_back().unwrap())
+                .find(|c| !c.is_space()),
+        )
+    });
+
+    Ok(Input {
+        problems: problems
+            .map(|(nums, op)| {
+                Ok(Problem {
+                    numbers: nums.collect(),
+                    op: match op {
+                        Some('+') => Op::Plus,
+                        Some('*') => Op::Times,
+                        Some(c) => {
+                            return Err(Error::Invalid(format!("unknown operator `{c}`")));
+                        }
+                        None => {
+                            return Err(Error::Invalid("a problem has no operator".to_owned()));
+                        }
+                    },
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn number_line(i: &mut &str) -> Result<Vec<u64>> {
+    columns(uint).parse_next(i)
+}
+
+fn op_line(i: &mut &str) -> Result<Vec<Op>> {
+    columns(
+        alt(('+'.value(Op::Plus), '*'.value(Op::Times)))
+            .context(StrContext::Expected(StrContextValue::CharLiteral('+')))
+            .context(StrContext::Expected(StrContextValue::CharLiteral('*'))),
+    )
+    .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winnow::Parser;
+
+    const INPUT: &str = include_str!("../../examples/day6/example.in");
+
+    #[test]
+    fn test_parse_1() {
+        assert_eq!(
+            parse_1(INPUT).unwrap(),
+            Input {
+                problems: vec![
+                    Problem {
+                        numbers: vec![123, 45, 6],
+                        op: Op::Times
+                    },
+                    Problem {
+                        numbers: vec![328, 64, 98],
+                        op: Op::Plus
+                    },
+                    Problem {
+                        numbers: vec![51, 387, 215],
+                        op: Op::Times
+                    },
+                    Problem {
+                        numbers: vec![64, 23, 314],
+                        op: Op::Plus
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_2() {
+        assert_eq!(
+            parse_2(INPUT).unwrap(),
+            Input {
+                problems: vec![
+                    Problem {
+                        numbers: vec![4, 431, 623],
+                        op: Op::Plus
+                    },
+                    Problem {
+                        numbers: vec![175, 581, 32],
+                        op: Op::Times
+                    },
+                    Problem {
+                        numbers: vec![8, 248, 369],
+                        op: Op::Plus
+                    },
+                    Problem {
+                        numbers: vec![356, 24, 1],
+                        op: Op::Times
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_number_line() {
+        assert_eq!(
+            number_line.parse(" 45 64  387 23 "),
+            Ok(vec![45, 64, 387, 23])
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(parse_1("1 2\n3\n+ *\n"), Err(Error::Invalid(_))));
+        assert!(matches!(parse_2("12 \n+ -\n"), Err(Error::Invalid(_))));
+        assert!(matches!(parse_2("12\n+  \n"), Err(Error::Invalid(_))));
+        assert!(matches!(parse_2(""), Err(Error::Invalid(_))));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let product = parse_1("4294967296 1\n4294967296 2\n* +\n").unwrap();
+        assert_eq!(
+            sum_solve(&product).unwrap_err().to_string(),
+            "invalid input: the answer to problem 1 is too large"
+        );
+
+        let total = parse_1("18446744073709551615 1\n+ +\n").unwrap();
+        assert_eq!(
+            sum_solve(&total).unwrap_err().to_string(),
+            "invalid input: the grand total is too large"
+        );
+    }
+}
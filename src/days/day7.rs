@@ -0,0 +1,176 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    Error, Solution,
+    grid::{Grid, Pos},
+};
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input {
+    start: Pos,
+    splitters: Vec<Pos>,
+    width: usize,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(part_2(input))
+    }
+}
+
+fn part_1(i: &Input) -> u64 {
+    let mut handled: HashSet<Pos> = HashSet::new();
+
+    count_splits(&i.start, i, &mut handled)
+}
+
+fn part_2(i: &Input) -> u64 {
+    let mut cache = HashMap::new();
+    count_timelines(&i.start, i, &mut cache) + 1
+}
+
+fn count_splits(start: &Pos, i: &Input, handled: &mut HashSet<Pos>) -> u64 {
+    match i
+        .splitters
+        .iter()
+        .filter(|&p| p.y > start.y && p.x == start.x)
+        .sorted_by_key(|p| p.y)
+        .next()
+    {
+        Some(s) if !handled.contains(s) => {
+            handled.insert(*s);
+            1 + split(s, i.width)
+                .map(|b| count_splits(&b, i, handled))
+                .sum::<u64>()
+        }
+        Some(_) => 0,
+        None => 0,
+    }
+}
+
+fn count_timelines(start: &Pos, i: &Input, cache: &mut HashMap<Pos, u64>) -> u64 {
+    if cache.contains_key(start) {
+        return *cache.get(start).unwrap();
+    }
+
+    let c = match i
+        .splitters
+        .iter()
+        .filter(|&p| p.y > start.y && p.x == start.x)
+        .sorted_by_key(|p| p.y)
+        .next()
+    {
+        Some(s) => {
+            1 + split(s, i.width)
+                .map(|b| count_timelines(&b, i, cache))
+                .sum::<u64>()
+        }
+        None => 0,
+    };
+
+    cache.insert(*start, c);
+
+    c
+}
+
+/// The beams that splitter `s` sends to its left and right. A beam that
+/// would leave the manifold, which is `width` wide, is lost.
+fn split(s: &Pos, width: usize) -> impl Iterator<Item = Pos> {
+    [s.x.checked_sub(1), Some(s.x + 1).filter(|&x| x < width)]
+        .into_iter()
+        .flatten()
+        .map(move |x| Pos::new(x, s.y))
+}
+
+fn parse(i: &str) -> Result<Input, Error> {
+    let grid = Grid::parse(i, |c| matches!(c, 'S' | '^' | '.').then_some(c))?;
+
+    let start = match grid.find_all(&'S').collect::<Vec<_>>()[..] {
+        [start] => start,
+        [] => return Err(Error::Invalid("there is no start".to_owned())),
+        _ => return Err(Error::Invalid("there is more than one start".to_owned())),
+    };
+
+    Ok(Input {
+        start,
+        splitters: grid.find_all(&'^').collect(),
+        width: grid.width(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/day7/example.in");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(INPUT).unwrap(),
+            Input {
+                start: Pos::new(7, 0),
+                splitters: vec![
+                    Pos::new(7, 2),
+                    Pos::new(6, 4),
+                    Pos::new(8, 4),
+                    Pos::new(5, 6),
+                    Pos::new(7, 6),
+                    Pos::new(9, 6),
+                    Pos::new(4, 8),
+                    Pos::new(6, 8),
+                    Pos::new(10, 8),
+                    Pos::new(3, 10),
+                    Pos::new(5, 10),
+                    Pos::new(9, 10),
+                    Pos::new(11, 10),
+                    Pos::new(2, 12),
+                    Pos::new(6, 12),
+                    Pos::new(12, 12),
+                    Pos::new(1, 14),
+                    Pos::new(3, 14),
+                    Pos::new(5, 14),
+                    Pos::new(7, 14),
+                    Pos::new(9, 14),
+                    Pos::new(13, 14)
+                ],
+                width: 15,
+            }
+        )
+    }
+
+    #[test]
+    fn test_edge_splitters() {
+        let left = parse("S.\n..\n^.\n").unwrap();
+        assert_eq!((part_1(&left), part_2(&left)), (1, 2));
+
+        let right = parse(".S\n..\n.^\n..\n^.\n").unwrap();
+        assert_eq!((part_1(&right), part_2(&right)), (2, 3));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(parse("...\n.^.\n"), Err(Error::Invalid(_))));
+        assert!(matches!(parse(".S.\n.S.\n"), Err(Error::Invalid(_))));
+        assert!(matches!(
+            parse(".S.\n.v.\n"),
+            Err(Error::Parse(d)) if (d.line, d.column) == (2, 2)
+        ));
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use crate::{
+    Error, Solution,
+    geometry::Point3,
+    kd_tree::KdTree,
+    parse::{lines, separated_array, uint},
+    union_find::UnionFind,
+};
+use itertools::Itertools;
+use winnow::{Parser, Result};
+
+type Pos = Point3<u64>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Pos>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let boxes = parse.parse(input)?;
+
+        if let Some(b) = boxes.iter().duplicates().next() {
+            return Err(Error::Invalid(format!(
+                "there is more than one junction box at {},{},{}",
+                b.x, b.y, b.z
+            )));
+        }
+
+        Ok(boxes)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        part_1(input, 1000)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {
+        part_2(input)
+    }
+}
+
+fn part_1(i: &[Pos], limit: usize) -> Result<usize, Error> {
+    let mut circuits = UnionFind::new(i.len());
+
+    closest_pairs(i)?.take(limit).for_each(|(a, b)| {
+        circuits.union(a, b);
+    });
+
+    Ok(circuits.sizes().sorted().rev().take(3).product())
+}
+
+fn part_2(i: &[Pos]) -> Result<u64, Error> {
+    let mut circuits = UnionFind::new(i.len());
+
+    let (a, b) = closest_pairs(i)?
+        .find(|&(a, b)| circuits.union(a, b) && circuits.components() == 1)
+        .ok_or_else(|| Error::Invalid("at least two junction boxes are needed".to_owned()))?;
+
+    i[a].x
+        .checked_mul(i[b].x)
+        .ok_or_else(|| Error::Invalid(format!("{} * {} is too large", i[a].x, i[b].x)))
+}
+
+/// Indices of all pairs of boxes, closest first.
+fn closest_pairs(i: &[Pos]) -> Result<impl Iterator<Item = (usize, usize)>, Error> {
+    let tree = KdTree::new(i).ok_or_else(|| {
+        Error::Invalid("the junction boxes are too far apart to compare".to_owned())
+    })?;
+
+    Ok(tree.closest_pairs())
+}
+
+fn parse(i: &mut &str) -> Result<Vec<Pos>> {
+    lines(separated_array(uint, ',').map(Pos::from)).parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/day8/example.in");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse.parse("1,2,3\n4,5,6\n"),
+            Ok(vec![Pos::new(1, 2, 3), Pos::new(4, 5, 6),])
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse.parse(INPUT).unwrap(), 10).unwrap(), 40);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            Day8::parse("1,2,3\n4,5,6\n1,2,3\n"),
+            Err(Error::Invalid(_))
+        ));
+        assert!(matches!(
+            part_2(&[Pos::new(1, 2, 3)]),
+            Err(Error::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let far = Day8::parse("0,0,0\n4294967296,0,0\n").unwrap();
+        assert!(matches!(part_1(&far, 1), Err(Error::Invalid(_))));
+        assert!(matches!(part_2(&far), Err(Error::Invalid(_))));
+
+        let wide = [Pos::new(1 << 32, 0, 0), Pos::new((1 << 32) + 1, 0, 0)];
+        assert!(matches!(part_2(&wide), Err(Error::Invalid(_))));
+    }
+}
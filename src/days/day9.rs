@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use crate::{
+    Error, Solution,
+    geometry::{Point2, Rect},
+    parse::{lines, separated_array, uint},
+};
+use itertools::Itertools;
+use winnow::{Parser, Result};
+
+type Pos = Point2<u64>;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Pos>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse.parse(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {
+        part_1(input)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<impl Display, Error> {
+        Err::<u64, _>(Error::Unsolved)
+    }
+}
+
+fn part_1(i: &[Pos]) -> Result<u64, Error> {
+    i.iter()
+        .tuple_combinations()
+        .map(|(&p1, &p2)| {
+            Rect::from_corners(p1, p2).area().ok_or_else(|| {
+                Error::Invalid(format!(
+                    "the rectangle between {},{} and {},{} is too large",
+                    p1.x, p1.y, p2.x, p2.y
+                ))
+            })
+        })
+        .process_results(|areas| areas.max())?
+        .ok_or_else(|| Error::Invalid("at least two red tiles are needed".to_owned()))
+}
+
+fn parse(i: &mut &str) -> Result<Vec<Pos>> {
+    lines(separated_array(uint, ',').map(Pos::from)).parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse.parse("1,2\n4,5\n"),
+            Ok(vec![Pos::new(1, 2), Pos::new(4, 5)])
+        );
+    }
+
+    #[test]
+    fn test_part_1_single_tile() {
+        assert!(matches!(part_1(&[Pos::new(1, 2)]), Err(Error::Invalid(_))));
+    }
+
+    #[test]
+    fn test_part_1_overflow() {
+        let tiles = Day9::parse("0,0\n18446744073709551615,18446744073709551615\n").unwrap();
+        assert!(matches!(part_1(&tiles), Err(Error::Invalid(_))));
+    }
+}
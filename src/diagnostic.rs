@@ -0,0 +1,194 @@
+//! Human-readable parse errors.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use winnow::error::{ContextError, ParseError, StrContext};
+
+/// Where and why an input failed to parse.
+///
+/// Displays like
+///
+/// ```text
+/// input/day8:2:4: invalid tuple
+///   |
+/// 2 | 4,5x,6
+///   |    ^ expected `,`
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The file the input came from, if known.
+    pub path: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The text of the offending line.
+    pub source_line: String,
+    pub message: String,
+    /// What would have been accepted at this position.
+    pub expected: Vec<String>,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic for the character at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |n| n + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |n| offset + n);
+
+        Diagnostic {
+            path: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.into(),
+            expected: vec![],
+        }
+    }
+
+    pub fn with_expected(mut self, expected: impl IntoIterator<Item = String>) -> Self {
+        self.expected.extend(expected);
+        self
+    }
+}
+
+impl From<ParseError<&str, ContextError>> for Diagnostic {
+    fn from(e: ParseError<&str, ContextError>) -> Self {
+        let input = *e.input();
+        let inner = e.inner();
+
+        let mut message = match inner.context().find_map(|c| match c {
+            StrContext::Label(l) => Some(*l),
+            _ => None,
+        }) {
+            Some(label) => format!("invalid {label}"),
+            None => match input[e.offset()..].chars().next() {
+                None => "unexpected end of input".to_owned(),
+                Some('\n' | '\r') => "unexpected end of line".to_owned(),
+                Some(c) => format!("unexpected `{}`", c.escape_debug()),
+            },
+        };
+        if let Some(cause) = inner.cause() {
+            message = format!("{message}: {cause}");
+        }
+
+        let expected = inner.context().filter_map(|c| match c {
+            StrContext::Expected(v) => Some(v.to_string()),
+            _ => None,
+        });
+
+        Diagnostic::at(input, e.offset(), message).with_expected(expected)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path.as_deref() {
+            Some(p) if p == Path::new("-") => write!(f, "<stdin>")?,
+            Some(p) => write!(f, "{}", p.display())?,
+            None => write!(f, "<input>")?,
+        }
+        writeln!(f, ":{}:{}: {}", self.line, self.column, self.message)?;
+
+        let gutter = self.line.to_string().len();
+        let indent: String = self
+            .source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{:gutter$} | {indent}^", "")?;
+
+        if let Some((last, rest)) = self.expected.split_last() {
+            write!(f, " expected ")?;
+            if !rest.is_empty() {
+                write!(f, "{} or ", rest.join(", "))?;
+            }
+            write!(f, "{last}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let d = Diagnostic::at("ab\ncäd\r\nef", 6, "oops");
+
+        assert_eq!((d.line, d.column), (2, 3));
+        assert_eq!(d.source_line, "cäd");
+
+        let d = Diagnostic::at("abc", 0, "");
+        assert_eq!((d.line, d.column), (1, 1));
+        assert_eq!(Diagnostic::at("ab\n", 3, "").source_line, "");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut d = Diagnostic::at("1,2,3\n4,5x,6\n", 9, "unexpected `x`")
+            .with_expected(["`,`".to_owned(), "number".to_owned()]);
+        d.path = Some(PathBuf::from("input/day8"));
+
+        assert_eq!(
+            d.to_string(),
+            concat!(
+                "input/day8:2:4: unexpected `x`\n",
+                "  |\n",
+                "2 | 4,5x,6\n",
+                "  |    ^ expected `,` or number",
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_parse_error() {
+        use crate::parse::{lines, separated_array, uint};
+        use winnow::Parser;
+
+        let e = lines(separated_array::<_, 2>(uint::<u8>, ','))
+            .parse("1,2\n3;4\n")
+            .unwrap_err();
+        let d = Diagnostic::from(e);
+
+        assert_eq!((d.line, d.column), (2, 2));
+        assert_eq!(d.message, "invalid tuple");
+        assert_eq!(d.expected, vec!["`,`".to_owned()]);
+
+        let e = lines(separated_array::<_, 3>(uint::<u8>, ','))
+            .parse("1,2,3\n4,5x,6\n")
+            .unwrap_err();
+        let mut d = Diagnostic::from(e);
+        d.path = Some(PathBuf::from("input/day8"));
+        assert_eq!(
+            d.to_string(),
+            concat!(
+                "input/day8:2:4: invalid tuple\n",
+                "  |\n",
+                "2 | 4,5x,6\n",
+                "  |    ^ expected `,`",
+            )
+        );
+
+        let e = lines(uint::<u8>).parse("1\n300\n").unwrap_err();
+        let d = Diagnostic::from(e);
+
+        assert_eq!(
+            (d.line, d.column, d.expected.clone()),
+            (2, 1, vec!["number".to_owned()])
+        );
+    }
+}
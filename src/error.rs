@@ -0,0 +1,93 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use winnow::error::{ContextError, ParseError};
+
+use crate::diagnostic::Diagnostic;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Reading an input failed.
+    Io { path: PathBuf, source: io::Error },
+    /// None of the locations searched for a day's input exist.
+    InputNotFound { day: u8, tried: Vec<PathBuf> },
+    /// The input does not match the day's grammar.
+    Parse(Box<Diagnostic>),
+    /// The input parsed, but does not describe a puzzle that can be solved.
+    Invalid(String),
+    /// A parameter of a day was not understood.
+    Param { name: String, message: String },
+    /// The part has not been solved yet.
+    Unsolved,
+    /// A request to the Advent of Code website failed or was refused.
+    Http { url: String, message: String },
+    /// Talking to the Advent of Code website needs a session token, and none
+    /// was found.
+    NoSession,
+    /// A file that would be overwritten exists already.
+    Exists(PathBuf),
+}
+
+impl Error {
+    /// Attributes a parse error to the input file at `path`.
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            Error::Parse(mut d) => {
+                d.path = Some(path.to_owned());
+                Error::Parse(d)
+            }
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "cannot read {}: {source}", path.display()),
+            Error::InputNotFound { day, tried } => {
+                write!(f, "no input for day {day}, tried:")?;
+                tried
+                    .iter()
+                    .try_for_each(|p| write!(f, "\n    {}", p.display()))
+            }
+            Error::Parse(d) => write!(f, "{d}"),
+            Error::Invalid(message) => write!(f, "invalid input: {message}"),
+            Error::Param { name, message } => write!(f, "parameter `{name}`: {message}"),
+            Error::Unsolved => write!(f, "not solved yet"),
+            Error::Http { url, message } => write!(f, "request to {url} failed: {message}"),
+            Error::Exists(path) => write!(f, "{} exists already", path.display()),
+            Error::NoSession => write!(
+                f,
+                "no session token, set ${} or save it to {}",
+                crate::site::SESSION_VAR,
+                crate::site::session_file().display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(d: Diagnostic) -> Self {
+        Error::Parse(Box::new(d))
+    }
+}
+
+impl From<ParseError<&str, ContextError>> for Error {
+    fn from(e: ParseError<&str, ContextError>) -> Self {
+        Error::Parse(Box::new(e.into()))
+    }
+}
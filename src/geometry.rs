@@ -0,0 +1,270 @@
+//! Points, distances and axis-aligned boxes in two and three dimensions.
+
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// Integers usable as coordinates.
+pub trait Coord: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// The distance between `self` and `other`, which does not underflow for
+    /// unsigned types.
+    fn abs_diff(self, other: Self) -> Self {
+        if self < other {
+            other - self
+        } else {
+            self - other
+        }
+    }
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        }
+    )*};
+}
+
+impl_coord!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+macro_rules! impl_point {
+    ($p:ident { $($f:ident),+ }) => {
+        impl<T> $p<T> {
+            pub const fn new($($f: T),+) -> Self {
+                $p { $($f),+ }
+            }
+        }
+
+        impl<T: Coord> $p<T> {
+            /// The component-wise distance to `other`.
+            pub fn abs_diff(self, other: Self) -> Self {
+                $p { $($f: self.$f.abs_diff(other.$f)),+ }
+            }
+
+            /// The square of the Euclidean distance to `other`, or `None` if
+            /// it does not fit in `T`.
+            pub fn dist_squared(self, other: Self) -> Option<T> {
+                let d = self.abs_diff(other);
+                [$(d.$f),+]
+                    .into_iter()
+                    .try_fold(T::ZERO, |sum, c| sum.checked_add(c.checked_mul(c)?))
+            }
+
+            /// The taxicab distance to `other`.
+            pub fn manhattan(self, other: Self) -> T {
+                let d = self.abs_diff(other);
+                [$(d.$f),+].into_iter().reduce(Add::add).unwrap()
+            }
+
+            /// The distance to `other` when diagonal steps are allowed.
+            pub fn chebyshev(self, other: Self) -> T {
+                let d = self.abs_diff(other);
+                [$(d.$f),+].into_iter().max().unwrap()
+            }
+
+            /// The smallest of each component.
+            pub fn min(self, other: Self) -> Self {
+                $p { $($f: self.$f.min(other.$f)),+ }
+            }
+
+            /// The largest of each component.
+            pub fn max(self, other: Self) -> Self {
+                $p { $($f: self.$f.max(other.$f)),+ }
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $p<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $p { $($f: self.$f + rhs.$f),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $p<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $p { $($f: self.$f - rhs.$f),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $p<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $p { $($f: self.$f * rhs),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $p<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$f += rhs.$f;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $p<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$f -= rhs.$f;)+
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl<T> From<[T; 2]> for Point2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<[T; 3]> for Point3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+/// An axis-aligned rectangle, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+/// An axis-aligned box, including its faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+macro_rules! impl_box {
+    ($b:ident, $p:ident { $($f:ident),+ }, $size:ident) => {
+        impl<T: Coord> $b<T> {
+            /// The smallest box containing both `a` and `b`, which are any two
+            /// opposite corners.
+            pub fn from_corners(a: $p<T>, b: $p<T>) -> Self {
+                $b {
+                    min: a.min(b),
+                    max: a.max(b),
+                }
+            }
+
+            /// The number of points inside, counting the ones on the edges, or
+            /// `None` if it does not fit in `T`.
+            pub fn $size(&self) -> Option<T> {
+                let d = self.max - self.min;
+                [$(d.$f.checked_add(T::ONE)?),+]
+                    .into_iter()
+                    .try_fold(T::ONE, T::checked_mul)
+            }
+
+            pub fn contains(&self, p: $p<T>) -> bool {
+                $(self.min.$f <= p.$f && p.$f <= self.max.$f)&&+
+            }
+        }
+    };
+}
+
+impl_box!(Rect, Point2 { x, y }, area);
+impl_box!(Cuboid, Point3 { x, y, z }, volume);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point2::new(1, 2) + Point2::new(3, 4) * 2;
+        assert_eq!(p, Point2::new(7, 10));
+
+        p -= Point2::new(7, 0);
+        assert_eq!(p, Point2::new(0, 10));
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::new(1, 1, 1),
+            Point3::new(0, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point3::<u64>::new(1, 7, 3), Point3::new(4, 3, 3));
+
+        assert_eq!(a.abs_diff(b), Point3::new(3, 4, 0));
+        assert_eq!(a.dist_squared(b), Some(25));
+        assert_eq!(
+            Point2::<u64>::new(0, 0).dist_squared(Point2::new(1 << 32, 0)),
+            None
+        );
+        assert_eq!(
+            Point2::<u64>::new(0, 0).dist_squared(Point2::new(1 << 31, 1 << 31)),
+            Some(1 << 63)
+        );
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point2::new(-2, 5).manhattan(Point2::new(3, -1)), 11);
+    }
+
+    #[test]
+    fn test_rect() {
+        let r = Rect::from_corners(Point2::<u64>::new(11, 1), Point2::new(2, 5));
+
+        assert_eq!(r.min, Point2::new(2, 1));
+        assert_eq!(r.area(), Some(50));
+        assert!(r.contains(Point2::new(11, 5)));
+        assert!(!r.contains(Point2::new(12, 5)));
+        assert_eq!(Rect::from_corners(r.min, r.min).area(), Some(1));
+        assert_eq!(
+            Rect::from_corners(Point2::new(0, 0), Point2::new(u64::MAX, u64::MAX)).area(),
+            None
+        );
+        assert_eq!(
+            Rect::from_corners(Point2::new(0, 0), Point2::new(u64::MAX, 0)).area(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let c = Cuboid::from_corners(Point3::new(0, 0, 0), Point3::new(-1, 2, 3));
+
+        assert_eq!(c.volume(), Some(24));
+        assert_eq!(
+            Cuboid::from_corners(Point3::new(0, 0, 0), Point3::new(1 << 21, 1 << 21, 1 << 21))
+                .volume(),
+            None
+        );
+        assert!(c.contains(Point3::new(-1, 1, 3)));
+        assert!(!c.contains(Point3::new(1, 1, 1)));
+    }
+}
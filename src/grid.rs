@@ -0,0 +1,273 @@
+//! Rectangular 2D grids, as found in many puzzle inputs.
+
+use std::ops::{Index, IndexMut};
+
+use crate::{Result, diagnostic::Diagnostic, geometry::Point2};
+
+/// A position in a grid, with `x` the column and `y` the row, counted from the
+/// top left.
+pub type Pos = Point2<usize>;
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per line of `s`, mapping every character with `cell`.
+    ///
+    /// Fails on characters `cell` returns `None` for, and on lines that are
+    /// not as long as the first one.
+    pub fn parse(s: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        let mut offset = 0;
+
+        for raw in s.split_inclusive('\n') {
+            let line = raw.trim_end_matches(['\n', '\r']);
+            let before = cells.len();
+
+            for (c, char) in line.char_indices() {
+                cells.push(cell(char).ok_or_else(|| {
+                    Diagnostic::at(s, offset + c, format!("unexpected `{char}`"))
+                })?);
+            }
+
+            let w = cells.len() - before;
+            if let Some(width) = width
+                && width != w
+            {
+                return Err(
+                    Diagnostic::at(s, offset + line.len(), "row has the wrong length")
+                        .with_expected([format!("a row of {width} cells")])
+                        .into(),
+                );
+            }
+
+            width = Some(w);
+            offset += raw.len();
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, p: Pos) -> Option<&T> {
+        (p.y < self.height && p.x < self.width).then(|| &self.cells[p.y * self.width + p.x])
+    }
+
+    pub fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        (p.y < self.height && p.x < self.width).then(|| &mut self.cells[p.y * self.width + p.x])
+    }
+
+    /// The cells of row `r`, left to right.
+    ///
+    /// # Panics
+    ///
+    /// If `r` is out of bounds.
+    pub fn row(&self, r: usize) -> &[T] {
+        assert!(r < self.height, "row {r} out of bounds");
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    /// The cells of column `c`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `c` is out of bounds.
+    pub fn column(&self, c: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(c < self.width, "column {c} out of bounds");
+        self.cells.iter().skip(c).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(move |r| self.row(r))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// The orthogonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.neighbors(pos, &OFFSETS_4)
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.neighbors(pos, &OFFSETS_8)
+    }
+
+    fn neighbors(
+        &self,
+        p: Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width, self.height);
+
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let n = Point2::new(p.x.checked_add_signed(dx)?, p.y.checked_add_signed(dy)?);
+            (n.x < width && n.y < height).then_some(n)
+        })
+    }
+
+    /// The first position in row-major order holding `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// All positions holding `value`, in row-major order.
+    pub fn find_all(&self, value: &T) -> impl Iterator<Item = Pos>
+    where
+        T: PartialEq,
+    {
+        self.positions().filter(move |&p| self[p] == *value)
+    }
+
+    /// Renders the grid as text with one line per row, the inverse of
+    /// [`Grid::parse`].
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            s.extend(row.iter().map(&mut cell));
+            s.push('\n');
+        }
+
+        s
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid();
+
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Point2::new(0, 1)], 'd');
+        assert_eq!(g.get(Point2::new(0, 2)), None);
+        assert_eq!(g.get(Point2::new(3, 0)), None);
+        assert_eq!(Grid::parse("", Some).unwrap(), Grid::new(0, 0, ' '));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            Grid::parse("..\n.#\n", |c| (c == '.').then_some(())),
+            Err(Error::Parse(d)) if (d.line, d.column) == (2, 2)
+        ));
+        assert!(matches!(
+            Grid::parse("...\n..\n", Some),
+            Err(Error::Parse(d)) if (d.line, d.column) == (2, 3)
+        ));
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let g = grid();
+
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+        assert_eq!(g.rows().count(), 2);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let g = grid();
+
+        assert_eq!(
+            g.neighbors_4(Point2::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(
+            g.neighbors_4(Point2::new(1, 1)).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1), Point2::new(2, 1)]
+        );
+        assert_eq!(
+            g.neighbors_8(Point2::new(2, 0)).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(1, 1), Point2::new(2, 1)]
+        );
+        assert_eq!(g.neighbors_8(Point2::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_find_render() {
+        let mut g = grid();
+        g[Point2::new(2, 1)] = 'a';
+
+        assert_eq!(g.find(&'a'), Some(Point2::new(0, 0)));
+        assert_eq!(
+            g.find_all(&'a').collect::<Vec<_>>(),
+            vec![Point2::new(0, 0), Point2::new(2, 1)]
+        );
+        assert_eq!(g.find(&'z'), None);
+        assert_eq!(g.render(|&c| c), "abc\ndea\n");
+    }
+}
@@ -0,0 +1,263 @@
+//! Every answer submitted to the website, and what it said about them.
+//!
+//! A wrong answer locks a part for a while, so before submitting, a guess is
+//! checked against the earlier ones: an answer that was wrong before, or that
+//! lies outside the bounds set by answers that were too high or too low, is
+//! refused. The guesses file has one guess per line, in the order they were
+//! made:
+//!
+//! ```text
+//! # day part verdict answer
+//! 1 1 too-high 1200
+//! 1 1 correct 1092
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{Error, Result, input, records};
+
+/// Environment variable naming the guesses file.
+pub const GUESSES_VAR: &str = "AOC_GUESSES";
+
+/// The guesses file: `$AOC_GUESSES`, or `guesses` next to the inputs in the
+/// per-user data directory.
+pub fn default_path() -> PathBuf {
+    env::var_os(GUESSES_VAR)
+        .filter(|p| !p.is_empty())
+        .map_or_else(
+            || {
+                input::data_dir(|k| env::var_os(k))
+                    .map(|d| d.join(env!("CARGO_PKG_NAME")))
+                    .unwrap_or_default()
+                    .join("guesses")
+            },
+            PathBuf::from,
+        )
+}
+
+/// What the website said about a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "too-high",
+            Feedback::TooLow => "too-low",
+            Feedback::Wrong => "wrong",
+        })
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "correct" => Ok(Feedback::Correct),
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Why a guess is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The part was solved already, with this answer.
+    Solved(String),
+    /// The same answer was submitted before.
+    Repeated(Feedback),
+    /// The answer is at least this earlier guess, which was too high.
+    AboveBound(String),
+    /// The answer is at most this earlier guess, which was too low.
+    BelowBound(String),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Solved(a) => write!(f, "already solved, the answer is {a}"),
+            Rejection::Repeated(feedback) => write!(f, "already guessed, it was {feedback}"),
+            Rejection::AboveBound(a) => write!(f, "{a} was too high already"),
+            Rejection::BelowBound(a) => write!(f, "{a} was too low already"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Guesses {
+    entries: BTreeMap<(u8, u8), Vec<(String, Feedback)>>,
+}
+
+impl Guesses {
+    /// Reads the guesses file at `path`. A missing file has no guesses.
+    pub fn load(path: &Path) -> Result<Self> {
+        records::load(path, Self::parse)
+    }
+
+    /// Writes the guesses to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let io = |source| Error::Io {
+            path: path.to_owned(),
+            source,
+        };
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+        fs::write(path, self.to_string()).map_err(io)
+    }
+
+    fn parse(s: &str) -> Result<Self, (usize, String)> {
+        let mut guesses = Guesses::default();
+
+        records::parse(s, |mut fields| {
+            let day = fields.parse("day")?;
+            let part = fields.parse("part")?;
+            let feedback = fields.parse("verdict")?;
+            let answer = fields.rest("answer")?;
+
+            guesses.insert(day, part, answer, feedback);
+            Ok(())
+        })?;
+
+        Ok(guesses)
+    }
+
+    /// The guesses for a part, oldest first.
+    pub fn get(&self, day: u8, part: u8) -> &[(String, Feedback)] {
+        self.entries.get(&(day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: String, feedback: Feedback) {
+        self.entries
+            .entry((day, part))
+            .or_default()
+            .push((answer, feedback));
+    }
+
+    /// The largest answer that was too low and the smallest one that was too
+    /// high, among the numeric guesses. The answer lies strictly between them.
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let numbers = |want| {
+            self.get(day, part)
+                .iter()
+                .filter(move |&&(_, f)| f == want)
+                .filter_map(|(a, _)| a.parse::<i128>().ok())
+        };
+
+        (
+            numbers(Feedback::TooLow).max(),
+            numbers(Feedback::TooHigh).min(),
+        )
+    }
+
+    /// Checks that `answer` could still be right.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), Rejection> {
+        let guesses = self.get(day, part);
+
+        if let Some((a, _)) = guesses.iter().find(|&&(_, f)| f == Feedback::Correct) {
+            return Err(Rejection::Solved(a.clone()));
+        }
+        if let Some(&(_, f)) = guesses.iter().find(|(a, _)| a == answer) {
+            return Err(Rejection::Repeated(f));
+        }
+
+        if let Ok(n) = answer.parse::<i128>() {
+            match self.bounds(day, part) {
+                (_, Some(high)) if n >= high => {
+                    return Err(Rejection::AboveBound(high.to_string()));
+                }
+                (Some(low), _) if n <= low => return Err(Rejection::BelowBound(low.to_string())),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Guesses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part verdict answer")?;
+        self.entries.iter().try_for_each(|((day, part), guesses)| {
+            guesses
+                .iter()
+                .try_for_each(|(answer, feedback)| writeln!(f, "{day} {part} {feedback} {answer}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guesses() -> Guesses {
+        Guesses::parse(
+            "# day part verdict answer\n\
+             1 1 too-high 1200\n\
+             1 1 too-low 900\n\
+             1 1 too-high 1500\n\
+             1 1 wrong 1000\n\
+             2 1 correct 42\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let g = guesses();
+        assert_eq!(g.get(1, 1).len(), 4);
+        assert_eq!(g.get(1, 2), &[]);
+        assert_eq!(Guesses::parse(&g.to_string()), Ok(g));
+    }
+
+    #[test]
+    fn test_check() {
+        let g = guesses();
+
+        assert_eq!(g.bounds(1, 1), (Some(900), Some(1200)));
+        assert_eq!(g.check(1, 1, "1100"), Ok(()));
+        assert_eq!(g.check(1, 1, "abc"), Ok(()));
+        assert_eq!(
+            g.check(1, 1, "1000"),
+            Err(Rejection::Repeated(Feedback::Wrong))
+        );
+        assert_eq!(
+            g.check(1, 1, "1300"),
+            Err(Rejection::AboveBound("1200".to_owned()))
+        );
+        assert_eq!(
+            g.check(1, 1, "850"),
+            Err(Rejection::BelowBound("900".to_owned()))
+        );
+        assert_eq!(g.check(2, 1, "43"), Err(Rejection::Solved("42".to_owned())));
+        assert_eq!(g.check(2, 2, "43"), Ok(()));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Guesses::parse("1 2 wrong\n"),
+            Err((1, "missing answer".to_owned()))
+        );
+        assert_eq!(
+            Guesses::parse("\n1 2 close 3\n"),
+            Err((2, "bad verdict".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! Just enough HTTP/1.1 to talk to the Advent of Code website.
+//!
+//! `http://` URLs are requested directly over TCP, which is all the tests
+//! need. `https://` URLs are handed to the `curl` command, so that there is no
+//! TLS stack to build; its configuration is passed on standard input to keep
+//! the session cookie out of the process list.
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use crate::{Error, Result};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub method: &'a str,
+    pub url: &'a str,
+    pub headers: Vec<(&'a str, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl<'a> Request<'a> {
+    pub fn get(url: &'a str) -> Self {
+        Request {
+            method: "GET",
+            url,
+            headers: vec![],
+            body: None,
+        }
+    }
+
+    /// A POST of the form `fields`.
+    pub fn post_form(url: &'a str, fields: &[(&str, &str)]) -> Self {
+        let body = fields
+            .iter()
+            .map(|(k, v)| format!("{}={}", url_encode(k), url_encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        Request {
+            method: "POST",
+            url,
+            headers: vec![(
+                "Content-Type",
+                "application/x-www-form-urlencoded".to_owned(),
+            )],
+            body: Some(body),
+        }
+    }
+
+    pub fn header(mut self, name: &'a str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Sends the request and waits for the whole response. Any status is a
+    /// response; only failing to get one is an error.
+    pub fn send(&self) -> Result<Response> {
+        let result = if let Some(rest) = self.url.strip_prefix("http://") {
+            self.send_plain(rest)
+        } else if self.url.starts_with("https://") {
+            self.send_curl()
+        } else {
+            Err("only http:// and https:// URLs are supported".to_owned())
+        };
+
+        result.map_err(|message| Error::Http {
+            url: self.url.to_owned(),
+            message,
+        })
+    }
+
+    fn send_plain(&self, rest: &str) -> Result<Response, String> {
+        let (host, path) = rest.find('/').map_or((rest, "/"), |i| rest.split_at(i));
+
+        let mut request = format!(
+            "{} {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n",
+            self.method
+        );
+        for (name, value) in &self.headers {
+            request.push_str(&format!("{name}: {value}\r\n"));
+        }
+        let body = self.body.as_deref().unwrap_or_default();
+        if self.body.is_some() {
+            request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        request.push_str("\r\n");
+        request.push_str(body);
+
+        let io = |e: std::io::Error| e.to_string();
+        let mut stream = TcpStream::connect(address(host)).map_err(io)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(io)?;
+        stream.set_write_timeout(Some(TIMEOUT)).map_err(io)?;
+        stream.write_all(request.as_bytes()).map_err(io)?;
+
+        let mut raw = vec![];
+        stream.read_to_end(&mut raw).map_err(io)?;
+        parse_response(&raw)
+    }
+
+    fn send_curl(&self) -> Result<Response, String> {
+        let mut config = format!(
+            "url = {}\nrequest = {}\nsilent\nshow-error\nmax-time = {}\nwrite-out = \"\\n%{{http_code}}\"\n",
+            curl_quote(self.url),
+            curl_quote(self.method),
+            TIMEOUT.as_secs()
+        );
+        for (name, value) in &self.headers {
+            config.push_str(&format!(
+                "header = {}\n",
+                curl_quote(&format!("{name}: {value}"))
+            ));
+        }
+        if let Some(body) = &self.body {
+            config.push_str(&format!("data-binary = {}\n", curl_quote(body)));
+        }
+
+        let mut child = Command::new("curl")
+            .args(["--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("cannot run curl, which is needed for https: {e}"))?;
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(config.as_bytes())
+            .map_err(|e| e.to_string())?;
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout.rsplit_once('\n').ok_or("no status from curl")?;
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| format!("bad status `{status}`"))?,
+            body: body.to_owned(),
+        })
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, String> {
+    let end = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or("incomplete response")?;
+    let head = String::from_utf8_lossy(&raw[..end]);
+    let mut body = &raw[end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or_default();
+    let status = status_line
+        .split(' ')
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .ok_or(format!("bad status line `{status_line}`"))?;
+
+    let mut chunked = false;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            let len = value
+                .parse()
+                .map_err(|_| format!("bad Content-Length `{value}`"))?;
+            body = body.get(..len).ok_or("response body cut short")?;
+        }
+    }
+
+    let body = if chunked {
+        dechunk(body)?
+    } else {
+        body.to_vec()
+    };
+
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Decodes a body sent with `Transfer-Encoding: chunked`.
+fn dechunk(mut raw: &[u8]) -> Result<Vec<u8>, String> {
+    let mut body = vec![];
+
+    loop {
+        let line_end = raw
+            .windows(2)
+            .position(|w| w == b"\r\n")
+            .ok_or("bad chunk")?;
+        let size = String::from_utf8_lossy(&raw[..line_end]);
+        // Chunk extensions follow a `;`.
+        let size = size.split(';').next().unwrap_or_default().trim();
+        let size =
+            usize::from_str_radix(size, 16).map_err(|_| format!("bad chunk size `{size}`"))?;
+        if size == 0 {
+            return Ok(body);
+        }
+
+        let chunk = raw
+            .get(line_end + 2..line_end + 2 + size)
+            .ok_or("chunk cut short")?;
+        body.extend_from_slice(chunk);
+        raw = raw.get(line_end + 4 + size..).ok_or("chunk cut short")?;
+    }
+}
+
+/// Quotes `s` as a string in a curl config file.
+fn curl_quote(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Encodes `s` for an `application/x-www-form-urlencoded` body.
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(b).to_string()
+            }
+            b' ' => "+".to_owned(),
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// The address to connect to for the `host` of an `http://` URL, on port 80
+/// unless it has one.
+fn address(host: &str) -> String {
+    let has_port = match host.rsplit_once(']') {
+        // An IPv6 address, which contains colons itself.
+        Some((_, port)) => port.starts_with(':'),
+        None => host.contains(':'),
+    };
+
+    if has_port {
+        host.to_owned()
+    } else {
+        format!("{host}:80")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let r = parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\nabcdef").unwrap();
+        assert_eq!((r.status, r.body.as_str()), (200, "abc"));
+
+        let r = parse_response(
+            b"HTTP/1.1 404 Not Found\r\ntransfer-encoding: chunked\r\n\r\n4;x=y\r\nnot \r\n5\r\nfound\r\n0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!((r.status, r.body.as_str()), (404, "not found"));
+
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+        assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\nabc").is_err());
+    }
+
+    #[test]
+    fn test_address() {
+        assert_eq!(address("localhost"), "localhost:80");
+        assert_eq!(address("localhost:8080"), "localhost:8080");
+        assert_eq!(address("127.0.0.1"), "127.0.0.1:80");
+        assert_eq!(address("[::1]"), "[::1]:80");
+        assert_eq!(address("[::1]:8080"), "[::1]:8080");
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(url_encode("a b&c=1~é"), "a+b%26c%3D1~%C3%A9");
+        assert_eq!(curl_quote("a \"b\"\\\n"), r#""a \"b\"\\\n""#);
+    }
+}
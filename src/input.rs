@@ -0,0 +1,115 @@
+//! Locating puzzle inputs.
+
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Result};
+
+/// Environment variable naming a directory that holds `day<N>` input files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Finds the input file for `day`.
+///
+/// An `explicit` path (or `-` for standard input) is always used as is.
+/// Otherwise the first existing file of [`candidates`] is chosen, and the
+/// error lists all of them if there is none.
+pub fn resolve(day: u8, explicit: Option<&Path>) -> Result<PathBuf> {
+    if let Some(path) = explicit {
+        return Ok(path.to_owned());
+    }
+
+    let tried = candidates(day, |k| env::var_os(k));
+    tried
+        .iter()
+        .find(|p| p.is_file())
+        .cloned()
+        .ok_or(Error::InputNotFound { day, tried })
+}
+
+/// The locations searched for the input of `day`, in order:
+///
+/// 1. `$AOC_INPUT_DIR/day<N>`,
+/// 2. `day<N>` in the per-user data directory, e.g.
+///    `~/.local/share/aoc-2025/day<N>`,
+/// 3. `input/day<N>` in the repository.
+///
+/// `var` looks up environment variables.
+pub fn candidates(day: u8, var: impl Fn(&str) -> Option<OsString>) -> Vec<PathBuf> {
+    let file = format!("day{day}");
+
+    [
+        var(INPUT_DIR_VAR)
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from),
+        data_dir(&var).map(|d| d.join(env!("CARGO_PKG_NAME"))),
+        Some(Path::new(env!("CARGO_MANIFEST_DIR")).join("input")),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|d| d.join(&file))
+    .collect()
+}
+
+/// The per-user data directory, e.g. `~/.local/share`.
+pub(crate) fn data_dir(var: impl Fn(&str) -> Option<OsString>) -> Option<PathBuf> {
+    let var = |k| var(k).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+    if let Some(d) = var("XDG_DATA_HOME") {
+        return Some(d);
+    }
+
+    if cfg!(windows) {
+        var("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|h| h.join("Library/Application Support"))
+    } else {
+        var("HOME").map(|h| h.join(".local/share"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo_default(day: u8) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("input/day{day}"))
+    }
+
+    #[test]
+    fn test_candidates() {
+        let var = |k: &str| match k {
+            INPUT_DIR_VAR => Some("/inputs".into()),
+            "XDG_DATA_HOME" => Some("/data".into()),
+            _ => None,
+        };
+
+        assert_eq!(
+            candidates(3, var),
+            vec![
+                PathBuf::from("/inputs/day3"),
+                PathBuf::from("/data/aoc-2025/day3"),
+                repo_default(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_candidates_unset() {
+        assert_eq!(candidates(12, |_| None), vec![repo_default(12)]);
+        assert_eq!(
+            candidates(12, |k| (k == INPUT_DIR_VAR).then(OsString::new)),
+            vec![repo_default(12)]
+        );
+    }
+
+    #[test]
+    fn test_resolve_explicit() {
+        assert_eq!(
+            resolve(1, Some(Path::new("-"))).unwrap(),
+            PathBuf::from("-")
+        );
+    }
+}
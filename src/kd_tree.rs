@@ -0,0 +1,280 @@
+//! Nearest-neighbor queries over points in 3D space.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::geometry::{Coord, Cuboid, Point3};
+
+/// A k-d tree over a slice of points, which are referred to by their index.
+///
+/// Distances are squared Euclidean. Points at the same distance are ordered
+/// by index, so all results are deterministic.
+#[derive(Debug, Clone)]
+pub struct KdTree<'a, T> {
+    points: &'a [Point3<T>],
+    /// Indices into `points`. Every subslice is a subtree with its root in
+    /// the middle, split along the axis given by its depth.
+    nodes: Vec<usize>,
+    /// The smallest box around the points, if there are any.
+    bounds: Option<Cuboid<T>>,
+}
+
+impl<'a, T: Coord> KdTree<'a, T> {
+    /// `None` if the squared distance between two of `points` does not fit in
+    /// `T`.
+    pub fn new(points: &'a [Point3<T>]) -> Option<Self> {
+        let min = points.iter().copied().reduce(Point3::min);
+        let max = points.iter().copied().reduce(Point3::max);
+        let bounds = min.zip(max).map(|(min, max)| Cuboid { min, max });
+        if let Some(b) = bounds {
+            b.min.dist_squared(b.max)?;
+        }
+
+        let mut nodes = (0..points.len()).collect::<Vec<_>>();
+        build(points, &mut nodes, 0);
+
+        Some(KdTree {
+            points,
+            nodes,
+            bounds,
+        })
+    }
+
+    /// The indices of the `k` points closest to `p`, closest first, or `None`
+    /// if the squared distance from `p` to one of them does not fit in `T`.
+    pub fn nearest(&self, p: Point3<T>, k: usize) -> Option<Vec<usize>> {
+        if let Some(b) = self.bounds {
+            b.min.min(p).dist_squared(b.max.max(p))?;
+        }
+
+        Some(self.nearest_where(p, k, |_| true))
+    }
+
+    /// Like [`KdTree::nearest`], but only considers the points for which
+    /// `keep` returns `true`, and `p` has to be one whose distances fit.
+    fn nearest_where(&self, p: Point3<T>, k: usize, keep: impl Fn(usize) -> bool) -> Vec<usize> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(&self.nodes, 0, p, k, &keep, &mut best);
+        }
+
+        best.into_sorted_vec().into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Collects the best `k` candidates of the subtree `nodes` into `best`, a
+    /// max-heap of `(distance, index)`.
+    fn search(
+        &self,
+        nodes: &[usize],
+        depth: usize,
+        p: Point3<T>,
+        k: usize,
+        keep: &impl Fn(usize) -> bool,
+        best: &mut BinaryHeap<(T, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+
+        let mid = nodes.len() / 2;
+        let i = nodes[mid];
+        if keep(i) {
+            best.push((dist_squared(self.points[i], p), i));
+            if best.len() > k {
+                best.pop();
+            }
+        }
+
+        let (axis, split) = (depth % 3, self.points[i]);
+        let (near, far) = if coord(p, axis) < coord(split, axis) {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+
+        self.search(near, depth + 1, p, k, keep, best);
+
+        // Points beyond the splitting plane are at least this far away. At the
+        // same distance a point with a lower index could still win.
+        let plane = coord(p, axis).abs_diff(coord(split, axis));
+        if best.len() < k || best.peek().is_some_and(|&(d, _)| plane * plane <= d) {
+            self.search(far, depth + 1, p, k, keep, best);
+        }
+    }
+
+    /// Every pair of points `(i, j)` with `i < j`, closest first.
+    ///
+    /// Pairs are produced lazily: each point keeps a batch of its nearest
+    /// neighbors, which is grown only once it has been used up.
+    pub fn closest_pairs(self) -> ClosestPairs<'a, T> {
+        let mut pairs = ClosestPairs {
+            streams: vec![Stream::default(); self.points.len()],
+            heap: BinaryHeap::new(),
+            tree: self,
+        };
+
+        for i in 0..pairs.streams.len() {
+            pairs.advance(i);
+        }
+
+        pairs
+    }
+}
+
+fn build<T: Coord>(points: &[Point3<T>], nodes: &mut [usize], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+
+    let mid = nodes.len() / 2;
+    let axis = depth % 3;
+    nodes.select_nth_unstable_by_key(mid, |&i| coord(points[i], axis));
+
+    let (left, right) = nodes.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+/// The squared distance between two points whose distances were checked to
+/// fit when the tree was built.
+fn dist_squared<T: Coord>(a: Point3<T>, b: Point3<T>) -> T {
+    a.dist_squared(b)
+        .expect("the distances of the points fit in T")
+}
+
+fn coord<T: Copy>(p: Point3<T>, axis: usize) -> T {
+    match axis {
+        0 => p.x,
+        1 => p.y,
+        _ => p.z,
+    }
+}
+
+/// The neighbors of a point with a greater index, in order of distance.
+#[derive(Debug, Clone, Default)]
+struct Stream {
+    found: Vec<usize>,
+    next: usize,
+    exhausted: bool,
+}
+
+/// Iterator returned by [`KdTree::closest_pairs`].
+#[derive(Debug, Clone)]
+pub struct ClosestPairs<'a, T> {
+    tree: KdTree<'a, T>,
+    streams: Vec<Stream>,
+    /// The next pair of every stream that has one.
+    heap: BinaryHeap<Reverse<(T, usize, usize)>>,
+}
+
+impl<T: Coord> ClosestPairs<'_, T> {
+    /// Pushes the next pair of point `i` onto the heap, if there is one.
+    fn advance(&mut self, i: usize) {
+        let stream = &mut self.streams[i];
+
+        if stream.next == stream.found.len() && !stream.exhausted {
+            let k = (2 * stream.found.len()).max(4);
+            stream.found = self.tree.nearest_where(self.tree.points[i], k, |j| j > i);
+            stream.exhausted = stream.found.len() < k;
+        }
+
+        if let Some(&j) = stream.found.get(stream.next) {
+            stream.next += 1;
+            let d = dist_squared(self.tree.points[i], self.tree.points[j]);
+            self.heap.push(Reverse((d, i, j)));
+        }
+    }
+}
+
+impl<T: Coord> Iterator for ClosestPairs<'_, T> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((_, i, j)) = self.heap.pop()?;
+        self.advance(i);
+
+        Some((i, j))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn brute_pairs(points: &[Point3<u64>]) -> Vec<(usize, usize)> {
+        (0..points.len())
+            .tuple_combinations()
+            .sorted_by_key(|&(i, j)| (points[i].dist_squared(points[j]), i, j))
+            .collect()
+    }
+
+    #[test]
+    fn test_nearest() {
+        let points = [[0, 0, 0], [5, 5, 5], [1, 0, 0], [0, 2, 0], [9, 9, 9]].map(Point3::from);
+        let tree = KdTree::new(&points).unwrap();
+
+        assert_eq!(tree.nearest(Point3::new(0, 0, 0), 3), Some(vec![0, 2, 3]));
+        assert_eq!(tree.nearest(Point3::new(8, 8, 8), 2), Some(vec![4, 1]));
+        assert_eq!(tree.nearest(Point3::new(8, 8, 8), 10).unwrap().len(), 5);
+        assert_eq!(tree.nearest(Point3::new(8, 8, 8), 0), Some(vec![]));
+        assert_eq!(tree.nearest(Point3::new(1 << 20, 0, 0), 1), None);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let points = [[0, 0, 0], [1 << 32, 0, 0]].map(Point3::<u64>::from);
+        assert!(KdTree::new(&points).is_none());
+
+        let points = [[0, 0, 0], [1 << 31, 1 << 31, 0]].map(Point3::<u64>::from);
+        assert_eq!(
+            KdTree::new(&points).unwrap().closest_pairs().next(),
+            Some((0, 1))
+        );
+    }
+
+    #[test]
+    fn test_closest_pairs() {
+        let points = [[0, 0, 0], [10, 0, 0], [0, 3, 0], [10, 1, 0]].map(Point3::from);
+
+        assert_eq!(
+            KdTree::new(&points)
+                .unwrap()
+                .closest_pairs()
+                .take(3)
+                .collect::<Vec<_>>(),
+            vec![(1, 3), (0, 2), (0, 1)]
+        );
+        assert_eq!(
+            KdTree::<u64>::new(&[]).unwrap().closest_pairs().next(),
+            None
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_closest_pairs_prop(
+            points in vec((0..8u64, 0..8u64, 0..8u64).prop_map(|(x, y, z)| Point3::new(x, y, z)), 0..40)
+        ) {
+            prop_assert_eq!(
+                KdTree::new(&points).unwrap().closest_pairs().collect::<Vec<_>>(),
+                brute_pairs(&points)
+            );
+        }
+
+        #[test]
+        fn test_nearest_prop(
+            points in vec((0..100u64, 0..100u64, 0..100u64).prop_map(|(x, y, z)| Point3::new(x, y, z)), 1..60),
+            p in (0..100u64, 0..100u64, 0..100u64).prop_map(|(x, y, z)| Point3::new(x, y, z)),
+            k in 0..10usize,
+        ) {
+            let expected = (0..points.len())
+                .sorted_by_key(|&i| (points[i].dist_squared(p), i))
+                .take(k)
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(KdTree::new(&points).unwrap().nearest(p, k), Some(expected));
+        }
+    }
+}
@@ -1,14 +1,74 @@
-use winnow::{Parser, combinator::delimited, error::ParserError, stream::Stream};
-
-pub fn surrounded<Input, Output, Error, ParseNext, Border, Ignored>(
-    parser: ParseNext,
-    border: Border,
-) -> impl Parser<Input, Output, Error>
-where
-    Input: Stream,
-    Error: ParserError<Input>,
-    ParseNext: Parser<Input, Output, Error>,
-    Border: Parser<Input, Ignored, Error> + Clone,
-{
-    delimited(border.clone(), parser, border)
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod diagnostic;
+mod error;
+pub mod geometry;
+pub mod grid;
+pub mod guesses;
+pub mod http;
+pub mod input;
+pub mod kd_tree;
+pub mod params;
+pub mod parse;
+pub mod range_set;
+mod records;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod site;
+#[cfg(test)]
+mod temp_dir;
+pub mod union_find;
+pub mod watch;
+
+pub use error::{Error, Result};
+pub use params::Params;
+pub use parse::surrounded;
+pub use range_set::RangeSet;
+
+/// A solution to a single day's puzzle.
+///
+/// The raw puzzle input is parsed once into [`Solution::Input`], which is then
+/// shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Applies `params` to the parsed input. Days without parameters accept
+    /// none.
+    fn configure(_input: &mut Self::Input, params: &Params) -> Result<()> {
+        params.only(&[])
+    }
+
+    fn part_1(input: &Self::Input) -> Result<impl Display>;
+    fn part_2(input: &Self::Input) -> Result<impl Display>;
+
+    /// How the answers come about, step by step, for days that can show it.
+    fn trace(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Reads the puzzle input at `path`, or standard input if `path` is `-`.
+pub fn read_input(path: &Path) -> Result<String> {
+    let res = if path == Path::new("-") {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s).map(|_| s)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    res.map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })
 }
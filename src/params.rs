@@ -0,0 +1,81 @@
+//! Settings of a day beyond its input, such as the size of a dial, given on
+//! the command line as `--param <name>=<value>`.
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Adds a parameter given as `name=value`, replacing an earlier value.
+    pub fn set(&mut self, arg: &str) -> Result<(), String> {
+        match arg.split_once('=') {
+            Some((name, value)) if !name.is_empty() => {
+                self.values.insert(name.to_owned(), value.to_owned());
+                Ok(())
+            }
+            _ => Err(format!("expected <name>=<value>, got `{arg}`")),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of parameter `name`, if it was given.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.values
+            .get(name)
+            .map(|v| {
+                v.parse().map_err(|_| Error::Param {
+                    name: name.to_owned(),
+                    message: format!("invalid value `{v}`"),
+                })
+            })
+            .transpose()
+    }
+
+    /// Fails if a parameter other than `known` was given.
+    pub fn only(&self, known: &[&str]) -> Result<()> {
+        match self.values.keys().find(|k| !known.contains(&k.as_str())) {
+            Some(name) => Err(Error::Param {
+                name: name.clone(),
+                message: "unknown parameter".to_owned(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let mut p = Params::default();
+        p.set("size=40").unwrap();
+        p.set("start=x").unwrap();
+        p.set("start=3").unwrap();
+        assert!(p.set("=3").is_err() && p.set("size").is_err());
+
+        assert_eq!(p.get::<u64>("size").unwrap(), Some(40));
+        assert_eq!(p.get::<u64>("start").unwrap(), Some(3));
+        assert_eq!(p.get::<u64>("other").unwrap(), None);
+        assert!(p.only(&["size", "start"]).is_ok());
+        assert_eq!(
+            p.only(&["size"]).unwrap_err().to_string(),
+            "parameter `start`: unknown parameter"
+        );
+
+        p.set("size=-1").unwrap();
+        assert_eq!(
+            p.get::<u64>("size").unwrap_err().to_string(),
+            "parameter `size`: invalid value `-1`"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Parsers for the shapes puzzle inputs usually come in.
+//!
+//! Everything here works on `&str` with winnow's [`ContextError`], like the
+//! days' own parsers, and composes with plain winnow combinators:
+//!
+//! ```
+//! use aoc_2025::parse::{lines, range_inclusive, uint};
+//! use winnow::Parser;
+//!
+//! let ranges = lines(range_inclusive(uint::<u64>)).parse("3-5\n10-14\n");
+//! assert_eq!(ranges, Ok(vec![3..=5, 10..=14]));
+//! ```
+
+use std::ops::RangeInclusive;
+
+use winnow::{
+    Parser, Result,
+    ascii::{dec_uint, line_ending, space0, space1},
+    combinator::{alt, delimited, eof, peek, preceded, repeat_till, separated, terminated},
+    error::{ContextError, ParserError, StrContext, StrContextValue},
+    stream::Stream,
+};
+
+pub use winnow::ascii::Uint;
+
+pub fn surrounded<Input, Output, Error, ParseNext, Border, Ignored>(
+    parser: ParseNext,
+    border: Border,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+    Border: Parser<Input, Ignored, Error> + Clone,
+{
+    delimited(border.clone(), parser, border)
+}
+
+/// An unsigned decimal number.
+pub fn uint<T: Uint>(i: &mut &str) -> Result<T> {
+    dec_uint
+        .context(StrContext::Expected(StrContextValue::Description("number")))
+        .parse_next(i)
+}
+
+/// One or more lines of `item`, up to the end of the input or a blank line.
+/// The line ending after the last line is optional.
+///
+/// Every line has to match, so errors point at the offending line instead of
+/// the end of the input.
+pub fn lines<'i, O>(
+    item: impl Parser<&'i str, O, ContextError>,
+) -> impl Parser<&'i str, Vec<O>, ContextError> {
+    repeat_till(
+        1..,
+        terminated(item, alt((line_ending, eof))).context(StrContext::Label("line")),
+        peek(alt((eof, line_ending))),
+    )
+    .map(|(v, _)| v)
+}
+
+/// One or more blocks of `item`, separated by blank lines.
+///
+/// `item` is expected to consume the line ending of its last line, as
+/// [`lines`] does.
+pub fn paragraphs<'i, O>(
+    item: impl Parser<&'i str, O, ContextError>,
+) -> impl Parser<&'i str, Vec<O>, ContextError> {
+    separated(
+        1..,
+        item.context(StrContext::Label("paragraph")),
+        line_ending,
+    )
+}
+
+/// Exactly `N` of `item`, separated by `sep`, such as the `x,y,z` of a
+/// coordinate.
+///
+/// ```
+/// use aoc_2025::parse::{separated_array, uint};
+/// use winnow::Parser;
+///
+/// assert_eq!(separated_array(uint::<u32>, ',').parse("1,2,3"), Ok([1, 2, 3]));
+/// assert!(separated_array::<_, 3>(uint::<u32>, ',').parse("1,2").is_err());
+/// ```
+pub fn separated_array<'i, O, const N: usize>(
+    item: impl Parser<&'i str, O, ContextError>,
+    sep: char,
+) -> impl Parser<&'i str, [O; N], ContextError> {
+    let sep = sep.context(StrContext::Expected(StrContextValue::CharLiteral(sep)));
+
+    separated(N, item, sep)
+        .map(|v: Vec<O>| {
+            v.try_into()
+                .ok()
+                .expect("separated() yields exactly N items")
+        })
+        .context(StrContext::Label("tuple"))
+}
+
+/// An inclusive range written as `start-end`.
+pub fn range_inclusive<'i, O>(
+    mut bound: impl Parser<&'i str, O, ContextError>,
+) -> impl Parser<&'i str, RangeInclusive<O>, ContextError> {
+    (move |i: &mut &'i str| {
+        let start = bound.parse_next(i)?;
+        '-'.parse_next(i)?;
+        let end = bound.parse_next(i)?;
+        Ok(start..=end)
+    })
+    .context(StrContext::Label("range"))
+}
+
+/// Items on one line that are separated, and possibly surrounded, by spaces,
+/// as in a table with aligned columns.
+///
+/// Like [`lines`], errors point at the offending item. The line ending is
+/// not consumed.
+pub fn columns<'i, O>(
+    item: impl Parser<&'i str, O, ContextError>,
+) -> impl Parser<&'i str, Vec<O>, ContextError> {
+    let end = || peek(alt((line_ending, eof)));
+
+    preceded(
+        space0,
+        repeat_till(
+            1..,
+            terminated(item, alt((space1, end()))).context(StrContext::Label("column")),
+            end(),
+        ),
+    )
+    .map(|(v, _)| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winnow::combinator::separated_pair;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines(uint::<u8>).parse("1\n2\n3\n"), Ok(vec![1, 2, 3]));
+        assert_eq!(lines(uint::<u8>).parse("1\n2"), Ok(vec![1, 2]));
+        assert!(lines(uint::<u8>).parse("").is_err());
+        assert!(lines(uint::<u8>).parse("1\n\n2\n").is_err());
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        assert_eq!(
+            paragraphs(lines(uint::<u8>)).parse("1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            separated_pair(lines(uint::<u8>), line_ending, lines('x')).parse("1\n\nx\nx"),
+            Ok((vec![1], vec!['x', 'x']))
+        );
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        assert_eq!(range_inclusive(uint::<u64>).parse("11-22"), Ok(11..=22));
+        assert!(range_inclusive(uint::<u64>).parse("11-").is_err());
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(
+            columns(uint::<u64>).parse(" 45 64  387 23 "),
+            Ok(vec![45, 64, 387, 23])
+        );
+        assert_eq!(columns('*').parse("*   *"), Ok(vec!['*', '*']));
+        assert!(columns('*').parse("**").is_err());
+
+        let e = columns(uint::<u64>).parse("1 2 x").unwrap_err();
+        assert_eq!(e.offset(), 4);
+        assert_eq!(e.inner().to_string(), "invalid column\nexpected number");
+    }
+
+    #[test]
+    fn test_context() {
+        let label = |e: &ContextError| {
+            e.context()
+                .filter_map(|c| match c {
+                    StrContext::Label(l) => Some(*l),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let e = lines(uint::<u8>).parse("1\nx\n").unwrap_err();
+        assert_eq!(e.offset(), 2);
+        assert_eq!(e.inner().to_string(), "invalid line\nexpected number");
+
+        let e = paragraphs(lines(uint::<u8>))
+            .parse("1\nx\n\n2\n")
+            .unwrap_err();
+        assert_eq!(e.offset(), 2);
+        assert_eq!(label(e.inner()), ["line", "paragraph"]);
+
+        let e = lines(columns(range_inclusive(uint::<u8>)))
+            .parse("1-2 3-\n")
+            .unwrap_err();
+        assert_eq!(e.offset(), 6);
+        assert_eq!(label(e.inner()), ["range", "column", "line"]);
+    }
+}
@@ -0,0 +1,322 @@
+//! Sets of integers stored as disjoint ranges.
+
+use std::{fmt, ops::RangeInclusive};
+
+use itertools::Itertools;
+
+/// Integers that a [`RangeSet`] can hold.
+pub trait Discrete: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The next value, or `None` for [`Self::MAX`].
+    fn succ(self) -> Option<Self>;
+    /// The previous value, or `None` for [`Self::MIN`].
+    fn pred(self) -> Option<Self>;
+    /// The number of values in `lo..=hi`, where `lo <= hi`.
+    fn count(lo: Self, hi: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {$(
+        impl Discrete for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(lo: Self, hi: Self) -> u128 {
+                (hi as i128 - lo as i128) as u128 + 1
+            }
+        }
+    )*};
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers, kept as sorted ranges that neither overlap nor touch.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Discrete> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+
+    /// Builds a set from ranges that are sorted by their start, merging the
+    /// ones that overlap or touch.
+    fn from_sorted(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut set = Self::new();
+
+        for (lo, hi) in ranges {
+            match set.ranges.last_mut() {
+                Some((_, end)) if end.succ().is_none_or(|n| lo <= n) => *end = hi.max(*end),
+                _ => set.ranges.push((lo, hi)),
+            }
+        }
+
+        set
+    }
+
+    /// Adds every value of `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (lo, hi) = range.into_inner();
+        if lo > hi {
+            return;
+        }
+
+        // The ranges in `i..j` overlap or touch the new one.
+        let i = self
+            .ranges
+            .partition_point(|&(_, e)| e.succ().is_some_and(|n| n < lo));
+        let j = self
+            .ranges
+            .partition_point(|&(s, _)| hi.succ().is_none_or(|n| s <= n));
+
+        let merged = if i < j {
+            (lo.min(self.ranges[i].0), hi.max(self.ranges[j - 1].1))
+        } else {
+            (lo, hi)
+        };
+        self.ranges.splice(i..j, [merged]);
+    }
+
+    /// Whether `value` is in the set, in O(log n).
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set. Cannot overflow, even for a set of
+    /// every `u64`.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|&(s, e)| T::count(s, e)).sum()
+    }
+
+    /// The disjoint ranges making up the set, in ascending order.
+    pub fn ranges(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// Every value in the set, in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_
+    where
+        RangeInclusive<T>: Iterator<Item = T>,
+    {
+        self.ranges().flatten()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_sorted(self.ranges.iter().merge(&other.ranges).copied())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (lo, hi) = (s1.max(s2), e1.min(e2));
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// The values in `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN..=T::MAX))
+    }
+
+    /// The values in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (lo, hi) = bounds.into_inner();
+        let mut ranges = vec![];
+        let mut next = (lo <= hi).then_some(lo);
+
+        for &(s, e) in &self.ranges {
+            let Some(n) = next else { break };
+            if s > hi {
+                break;
+            }
+            if e < n {
+                continue;
+            }
+
+            if s > n {
+                ranges.push((n, s.pred().expect("s > n >= MIN")));
+            }
+            next = e.succ();
+        }
+
+        if let Some(n) = next
+            && n <= hi
+        {
+            ranges.push((n, hi));
+        }
+
+        RangeSet { ranges }
+    }
+}
+
+impl<T: Discrete> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(lo, hi)| lo <= hi)
+            .collect::<Vec<_>>();
+        ranges.sort_unstable();
+
+        Self::from_sorted(ranges)
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|r| self.insert(r));
+    }
+}
+
+impl<T: Discrete + fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ranges()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<u8>]) -> RangeSet<u8> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn oracle(ranges: &[RangeInclusive<u8>]) -> BTreeSet<u8> {
+        ranges.iter().cloned().flatten().collect()
+    }
+
+    fn to_oracle(s: &RangeSet<u8>) -> BTreeSet<u8> {
+        s.values().collect()
+    }
+
+    /// Sorted, non-empty, and neither overlapping nor touching.
+    fn is_normalized(s: &RangeSet<u8>) -> bool {
+        s.ranges.iter().all(|(lo, hi)| lo <= hi)
+            && s.ranges
+                .windows(2)
+                .all(|w| w[0].1.succ().is_some_and(|n| n < w[1].0))
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<RangeInclusive<u8>>> {
+        vec((any::<u8>(), any::<u8>()).prop_map(|(a, b)| a..=b), 0..8)
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_insert() {
+        let mut s = RangeSet::new();
+        s.insert(10..=20);
+        s.insert(30..=40);
+        s.insert(5..=3);
+        assert_eq!(s.ranges().collect::<Vec<_>>(), vec![10..=20, 30..=40]);
+
+        s.insert(21..=29);
+        assert_eq!(s.ranges().collect::<Vec<_>>(), vec![10..=40]);
+
+        s.insert(0..=0);
+        s.insert(u8::MAX..=u8::MAX);
+        assert_eq!(
+            s.ranges().collect::<Vec<_>>(),
+            vec![0..=0, 10..=40, 255..=255]
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(RangeSet::from_iter([0..=u64::MAX]).len(), 1 << 64);
+        assert_eq!(RangeSet::from_iter([i64::MIN..=i64::MAX]).len(), 1 << 64);
+        assert_eq!(set(&[3..=5, 10..=14, 16..=20, 12..=18]).len(), 14);
+        assert!(RangeSet::<u8>::new().is_empty());
+    }
+
+    #[test]
+    fn test_complement() {
+        let s = set(&[3..=5, 10..=14]);
+
+        assert_eq!(s.complement(0..=20), set(&[0..=2, 6..=9, 15..=20]));
+        assert_eq!(s.complement(4..=12), set(&[6..=9]));
+        assert_eq!(s.complement(3..=5), set(&[]));
+        assert_eq!(
+            set(&[0..=3, 250..=255]).complement(0..=255),
+            set(&[4..=249])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_insert_prop(rs in ranges()) {
+            let mut s = RangeSet::new();
+            s.extend(rs.iter().cloned());
+
+            prop_assert!(is_normalized(&s));
+            prop_assert_eq!(&s, &set(&rs));
+            prop_assert_eq!(to_oracle(&s), oracle(&rs));
+            prop_assert_eq!(s.len(), oracle(&rs).len() as u128);
+            for v in 0..=u8::MAX {
+                prop_assert_eq!(s.contains(v), oracle(&rs).contains(&v));
+            }
+        }
+
+        #[test]
+        fn test_set_ops_prop(a in ranges(), b in ranges(), lo: u8, hi: u8) {
+            let (sa, sb) = (set(&a), set(&b));
+            let (oa, ob) = (oracle(&a), oracle(&b));
+
+            let union = sa.union(&sb);
+            let intersection = sa.intersection(&sb);
+            let difference = sa.difference(&sb);
+            let complement = sa.complement(lo..=hi);
+
+            for s in [&union, &intersection, &difference, &complement] {
+                prop_assert!(is_normalized(s));
+            }
+            prop_assert_eq!(to_oracle(&union), &oa | &ob);
+            prop_assert_eq!(to_oracle(&intersection), &oa & &ob);
+            prop_assert_eq!(to_oracle(&difference), &oa - &ob);
+            prop_assert_eq!(
+                to_oracle(&complement),
+                (lo..=hi).filter(|v| !oa.contains(v)).collect::<BTreeSet<_>>()
+            );
+        }
+    }
+}
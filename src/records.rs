@@ -0,0 +1,109 @@
+//! Files with one record of whitespace-separated fields per line, such as the
+//! answers and guesses files. Blank lines and lines starting with `#` are
+//! skipped.
+
+use std::{fs, io, path::Path, str::SplitWhitespace};
+
+use crate::{Error, Result};
+
+/// Reads the file at `path` with `parse`. A missing file is empty, which
+/// `parse` is not asked about.
+pub(crate) fn load<T: Default>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, (usize, String)>,
+) -> Result<T> {
+    match fs::read_to_string(path) {
+        Ok(s) => parse(&s).map_err(|(line, message)| {
+            Error::Invalid(format!("{}:{line}: {message}", path.display()))
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(source) => Err(Error::Io {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+/// Passes the fields of every record in `s` to `record`. Errors are the line
+/// number and what is wrong with it.
+pub(crate) fn parse<'a>(
+    s: &'a str,
+    mut record: impl FnMut(Fields<'a>) -> Result<(), String>,
+) -> Result<(), (usize, String)> {
+    for (n, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        record(Fields(line.split_whitespace())).map_err(|message| (n + 1, message))?;
+    }
+
+    Ok(())
+}
+
+/// The fields of a record, taken from the front. `name` describes the field
+/// in errors.
+pub(crate) struct Fields<'a>(SplitWhitespace<'a>);
+
+impl<'a> Fields<'a> {
+    pub(crate) fn next(&mut self, name: &str) -> Result<&'a str, String> {
+        self.0.next().ok_or_else(|| format!("missing {name}"))
+    }
+
+    /// The next field, converted with `f`.
+    pub(crate) fn map<T, E>(
+        &mut self,
+        name: &str,
+        f: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<T, String> {
+        f(self.next(name)?).map_err(|_| format!("bad {name}"))
+    }
+
+    pub(crate) fn parse<T: std::str::FromStr>(&mut self, name: &str) -> Result<T, String> {
+        self.map(name, str::parse)
+    }
+
+    /// The remaining fields, which may not be empty, joined by spaces.
+    pub(crate) fn rest(self, name: &str) -> Result<String, String> {
+        let rest = self.0.collect::<Vec<_>>().join(" ");
+        if rest.is_empty() {
+            return Err(format!("missing {name}"));
+        }
+
+        Ok(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut records = vec![];
+        let parsed = parse("# a b\n\n 1 x  y z\n2 w\n", |mut f| {
+            records.push((f.parse::<u8>("number")?, f.rest("text")?));
+            Ok(())
+        });
+
+        assert_eq!(parsed, Ok(()));
+        assert_eq!(records, [(1, "x y z".to_owned()), (2, "w".to_owned())]);
+
+        let hash = |s| {
+            parse(s, |mut f| {
+                f.map("hash", |h| u64::from_str_radix(h, 16)).map(drop)
+            })
+        };
+        assert_eq!(hash("\nff\n"), Ok(()));
+        assert_eq!(hash("# ff\n\nfg\n"), Err((3, "bad hash".to_owned())));
+        assert_eq!(parse("1\n", |f| f.rest("text").map(drop)), Ok(()));
+        assert_eq!(
+            parse("1\n", |mut f| {
+                f.next("number")?;
+                f.next("text").map(drop)
+            }),
+            Err((1, "missing text".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Machine-readable results, one record per day and part.
+//!
+//! Every record has the fields
+//!
+//! - `day` and `part`,
+//! - `status`: `ok`, `unsolved` or `error`,
+//! - `answer`, if there is one,
+//! - `time_ns`: how long solving the part took,
+//! - `parse_ns`: how long parsing the input took,
+//! - `input_hash`: the [`input_hash`](crate::answers::input_hash) of the
+//!   input as 16 hex digits,
+//! - `error`: the error message, for status `error`.
+//!
+//! Fields that don't apply are `null` in JSON and empty in CSV and TSV.
+
+use std::{fmt::Write, str::FromStr};
+
+use crate::{Error, runner::Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One JSON object per line.
+    Json,
+    /// Comma-separated values with a header line, quoted as in RFC 4180.
+    Csv,
+    /// Tab-separated values with a header line. Tabs, newlines and
+    /// backslashes in values are escaped as `\t`, `\n` and `\\`.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("unknown format `{s}`")),
+        }
+    }
+}
+
+const FIELDS: [&str; 8] = [
+    "day",
+    "part",
+    "status",
+    "answer",
+    "time_ns",
+    "parse_ns",
+    "input_hash",
+    "error",
+];
+
+/// The line to start the output with, if `format` has one.
+pub fn header(format: Format) -> Option<String> {
+    match format {
+        Format::Json => None,
+        Format::Csv => Some(FIELDS.join(",")),
+        Format::Tsv => Some(FIELDS.join("\t")),
+    }
+}
+
+/// Formats `outcome` as one line per part. `parts` are the parts that were
+/// asked for, which are all reported as errors if the input could not be
+/// read or parsed.
+pub fn to_lines(format: Format, outcome: &Outcome, parts: &[u8]) -> String {
+    let mut s = String::new();
+
+    let records = match &outcome.parts {
+        Ok(solved) => solved
+            .iter()
+            .map(|p| (p.part, p.answer.as_deref(), Some(p.time)))
+            .collect(),
+        Err(e) => parts.iter().map(|&p| (p, Err(e), None)).collect::<Vec<_>>(),
+    };
+
+    for (part, answer, time) in records {
+        let (status, error) = match answer {
+            Ok(_) => ("ok", None),
+            Err(Error::Unsolved) => ("unsolved", None),
+            Err(e) => ("error", Some(e.to_string())),
+        };
+        let values = [
+            Some(outcome.day.to_string()),
+            Some(part.to_string()),
+            Some(status.to_owned()),
+            answer.ok().map(str::to_owned),
+            time.map(|t| t.as_nanos().to_string()),
+            outcome.parse_time.map(|t| t.as_nanos().to_string()),
+            outcome.input_hash.map(|h| format!("{h:016x}")),
+            error,
+        ];
+
+        let _ = writeln!(s, "{}", record(format, &values));
+    }
+
+    s
+}
+
+fn record(format: Format, values: &[Option<String>; 8]) -> String {
+    match format {
+        Format::Json => {
+            let fields = FIELDS
+                .iter()
+                .zip(values)
+                .map(|(name, v)| match v {
+                    // Numbers stay numbers, everything else is a string.
+                    Some(v) if matches!(*name, "day" | "part" | "time_ns" | "parse_ns") => {
+                        format!(r#""{name}":{v}"#)
+                    }
+                    Some(v) => format!(r#""{name}":{}"#, json_string(v)),
+                    None => format!(r#""{name}":null"#),
+                })
+                .collect::<Vec<_>>();
+            format!("{{{}}}", fields.join(","))
+        }
+        Format::Csv => values
+            .iter()
+            .map(|v| match v.as_deref() {
+                Some(v) if v.contains([',', '"', '\n', '\r']) => {
+                    format!("\"{}\"", v.replace('"', "\"\""))
+                }
+                v => v.unwrap_or_default().to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join(","),
+        Format::Tsv => values
+            .iter()
+            .map(|v| {
+                v.as_deref()
+                    .unwrap_or_default()
+                    .replace('\\', "\\\\")
+                    .replace('\t', "\\t")
+                    .replace('\n', "\\n")
+                    .replace('\r', "\\r")
+            })
+            .collect::<Vec<_>>()
+            .join("\t"),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Part;
+    use std::time::Duration;
+
+    fn outcome() -> Outcome {
+        Outcome {
+            day: 6,
+            input_hash: Some(0xab),
+            parse_time: Some(Duration::from_nanos(7)),
+            parts: Ok(vec![
+                Part {
+                    part: 1,
+                    answer: Ok("a, \"b\"".to_owned()),
+                    time: Duration::from_nanos(10),
+                },
+                Part {
+                    part: 2,
+                    answer: Err(Error::Unsolved),
+                    time: Duration::from_nanos(1),
+                },
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            to_lines(Format::Json, &outcome(), &[1, 2]),
+            concat!(
+                r#"{"day":6,"part":1,"status":"ok","answer":"a, \"b\"","time_ns":10,"#,
+                r#""parse_ns":7,"input_hash":"00000000000000ab","error":null}"#,
+                "\n",
+                r#"{"day":6,"part":2,"status":"unsolved","answer":null,"time_ns":1,"#,
+                r#""parse_ns":7,"input_hash":"00000000000000ab","error":null}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_csv_tsv() {
+        assert_eq!(
+            to_lines(Format::Csv, &outcome(), &[1, 2]),
+            concat!(
+                "6,1,ok,\"a, \"\"b\"\"\",10,7,00000000000000ab,\n",
+                "6,2,unsolved,,1,7,00000000000000ab,\n",
+            )
+        );
+
+        let failed = Outcome {
+            day: 1,
+            input_hash: None,
+            parse_time: None,
+            parts: Err(Error::Invalid("a\tb\nc".to_owned())),
+        };
+        assert_eq!(
+            to_lines(Format::Tsv, &failed, &[2]),
+            "1\t2\terror\t\t\t\t\tinvalid input: a\\tb\\nc\n"
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"\\\n\u{1}é"), r#""a\"\\\n\u0001é""#);
+    }
+}
@@ -0,0 +1,307 @@
+//! Solving several days at once on a pool of threads.
+
+use std::{
+    collections::VecDeque,
+    num::NonZero,
+    path::Path,
+    sync::{Arc, Condvar, Mutex, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Params, Result,
+    answers::input_hash,
+    days::{Day, Parsed},
+    input, read_input,
+};
+
+/// A day to solve.
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    pub day: &'static Day,
+    /// The parts to solve, in the order they are reported.
+    pub parts: &'a [u8],
+    /// The input file, or `None` to look for it with [`input::resolve`].
+    pub input: Option<&'a Path>,
+    pub params: &'a Params,
+}
+
+/// The answers of one [`Job`].
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    /// The [`input_hash`] of the input, if it could be read.
+    pub input_hash: Option<u64>,
+    /// How long parsing took, if it succeeded.
+    pub parse_time: Option<Duration>,
+    /// Every part, or the error that kept the input from being read or
+    /// parsed.
+    pub parts: Result<Vec<Part>>,
+}
+
+#[derive(Debug)]
+pub struct Part {
+    pub part: u8,
+    pub answer: Result<String>,
+    pub time: Duration,
+}
+
+/// The number of threads to use by default: one per CPU.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZero::get)
+}
+
+/// Solves `jobs` on `threads` threads and passes their outcomes to `report`,
+/// in the order of `jobs`.
+///
+/// Reading and parsing the input of each day is one task, solving each part
+/// on the shared parsed input is another, so a slow day only holds up the
+/// threads that work on it. An outcome is reported as soon as it and all
+/// outcomes before it are complete.
+pub fn run(jobs: &[Job], threads: usize, mut report: impl FnMut(Outcome)) {
+    let queue = Queue {
+        state: Mutex::new(State {
+            tasks: (0..jobs.len()).map(Task::Load).collect(),
+            busy: 0,
+        }),
+        changed: Condvar::new(),
+    };
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..threads.max(1) {
+            let (queue, tx) = (&queue, tx.clone());
+            s.spawn(move || work(jobs, queue, tx));
+        }
+        drop(tx);
+
+        let mut pending = jobs
+            .iter()
+            .map(|j| {
+                Some(Outcome {
+                    day: j.day.number,
+                    input_hash: None,
+                    parse_time: None,
+                    parts: Ok(Vec::with_capacity(j.parts.len())),
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut next = 0;
+
+        for (j, message) in rx {
+            let outcome = pending[j].as_mut().expect("outcome was already reported");
+            match (message, &mut outcome.parts) {
+                (Message::Loaded(hash, parsed), _) => {
+                    outcome.input_hash = hash;
+                    match parsed {
+                        Ok(t) => outcome.parse_time = Some(t),
+                        Err(e) => outcome.parts = Err(e),
+                    }
+                }
+                (Message::Solved(part), Ok(parts)) => parts.push(part),
+                (Message::Solved(_), Err(_)) => unreachable!("parts of a failed day"),
+            }
+
+            while let Some(Some(o)) = pending.get(next)
+                && match &o.parts {
+                    Ok(parts) => o.parse_time.is_some() && parts.len() == jobs[next].parts.len(),
+                    Err(_) => true,
+                }
+            {
+                let mut o = pending[next].take().unwrap();
+                if let Ok(parts) = &mut o.parts {
+                    let order = |p| jobs[next].parts.iter().position(|&q| q == p);
+                    parts.sort_by_key(|p| order(p.part));
+                }
+                report(o);
+                next += 1;
+            }
+        }
+    });
+}
+
+enum Task {
+    /// Read and parse the input of a job.
+    Load(usize),
+    /// Solve a part of a job.
+    Solve(usize, u8, Arc<Parsed>),
+}
+
+enum Message {
+    /// The input hash, if the input could be read, and the parse time.
+    Loaded(Option<u64>, Result<Duration>),
+    Solved(Part),
+}
+
+struct Queue {
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+struct State {
+    tasks: VecDeque<Task>,
+    /// Tasks being worked on, which may still add more tasks.
+    busy: usize,
+}
+
+fn work(jobs: &[Job], queue: &Queue, tx: mpsc::Sender<(usize, Message)>) {
+    loop {
+        let task = {
+            let mut state = queue.state.lock().unwrap();
+            loop {
+                if let Some(t) = state.tasks.pop_front() {
+                    state.busy += 1;
+                    break t;
+                }
+                if state.busy == 0 {
+                    return;
+                }
+                state = queue.changed.wait(state).unwrap();
+            }
+        };
+        let _busy = Busy(queue);
+
+        let mut more = vec![];
+        match task {
+            Task::Load(j) => {
+                let (hash, parsed) = load(&jobs[j]);
+                let message = match parsed {
+                    Ok((parsed, time)) => {
+                        let parsed = Arc::new(parsed);
+                        more.extend(
+                            jobs[j]
+                                .parts
+                                .iter()
+                                .map(|&p| Task::Solve(j, p, parsed.clone())),
+                        );
+                        Message::Loaded(hash, Ok(time))
+                    }
+                    Err(e) => Message::Loaded(hash, Err(e)),
+                };
+                let _ = tx.send((j, message));
+            }
+            Task::Solve(j, part, parsed) => {
+                let start = Instant::now();
+                let answer = jobs[j].day.solve(part, &parsed);
+                let time = start.elapsed();
+                let _ = tx.send((j, Message::Solved(Part { part, answer, time })));
+            }
+        }
+
+        // Finish days that were started before starting new ones.
+        let mut state = queue.state.lock().unwrap();
+        for t in more.into_iter().rev() {
+            state.tasks.push_front(t);
+        }
+        drop(state);
+    }
+}
+
+/// Marks a task as done when dropped, even if it panicked, so that the other
+/// threads don't wait for it forever.
+struct Busy<'a>(&'a Queue);
+
+impl Drop for Busy<'_> {
+    fn drop(&mut self) {
+        self.0.state.lock().unwrap().busy -= 1;
+        self.0.changed.notify_all();
+    }
+}
+
+/// Reads and parses the input of `job`. Returns the input hash, if the input
+/// could be read, along with the parsed input and the time parsing took.
+fn load(job: &Job) -> (Option<u64>, Result<(Parsed, Duration)>) {
+    let input =
+        input::resolve(job.day.number, job.input).and_then(|path| Ok((read_input(&path)?, path)));
+    let (input, path) = match input {
+        Ok(i) => i,
+        Err(e) => return (None, Err(e)),
+    };
+
+    let start = Instant::now();
+    let parsed = job
+        .day
+        .parse_with(&input, job.params)
+        .map_err(|e| e.in_file(&path));
+    let time = start.elapsed();
+
+    (Some(input_hash(&input)), parsed.map(|p| (p, time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{Error, days};
+
+    fn answers(o: &Outcome) -> Vec<(u8, Result<&str, String>)> {
+        o.parts
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|p| (p.part, p.answer.as_deref().map_err(ToString::to_string)))
+            .collect()
+    }
+
+    #[test]
+    fn test_run() {
+        let example = |d| {
+            PathBuf::from(format!(
+                "{}/examples/day{d}/example.in",
+                env!("CARGO_MANIFEST_DIR")
+            ))
+        };
+        let (e1, e9, missing) = (example(1), example(9), PathBuf::from("no such file"));
+        let none = Params::default();
+        let mut small_dial = Params::default();
+        small_dial.set("size=10").unwrap();
+        small_dial.set("start=5").unwrap();
+        let jobs = [
+            Job {
+                day: days::get(9).unwrap(),
+                parts: &[2, 1],
+                input: Some(&*e9),
+                params: &none,
+            },
+            Job {
+                day: days::get(1).unwrap(),
+                parts: &[1, 2],
+                input: Some(&*e1),
+                params: &none,
+            },
+            Job {
+                day: days::get(1).unwrap(),
+                parts: &[1],
+                input: Some(&*missing),
+                params: &none,
+            },
+            Job {
+                day: days::get(1).unwrap(),
+                parts: &[2],
+                input: Some(&*e1),
+                params: &small_dial,
+            },
+        ];
+
+        for threads in [1, 4] {
+            let mut outcomes = vec![];
+            run(&jobs, threads, |o| outcomes.push(o));
+
+            assert_eq!(
+                outcomes.iter().map(|o| o.day).collect::<Vec<_>>(),
+                vec![9, 1, 1, 1]
+            );
+            assert_eq!(
+                answers(&outcomes[0]),
+                vec![(2, Err("not solved yet".to_owned())), (1, Ok("50"))]
+            );
+            assert_eq!(answers(&outcomes[1]), vec![(1, Ok("3")), (2, Ok("6"))]);
+            assert!(outcomes[1].input_hash.is_some() && outcomes[1].parse_time.is_some());
+            assert!(matches!(outcomes[2].parts, Err(Error::Io { .. })));
+            assert_eq!(outcomes[2].input_hash, None);
+            assert_eq!(answers(&outcomes[3]), vec![(2, Ok("46"))]);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! Generating the skeleton of a new day.
+//!
+//! A new day gets a module in `src/days/` in the same shape as the others, a
+//! placeholder example in `examples/day<N>/`, and an entry in the list of
+//! days in `src/days.rs`. Nothing that exists already is overwritten.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Result};
+
+/// Creates day `day` in the repository at `root` and returns the files that
+/// were created or changed.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let module = root.join(format!("src/days/day{day}.rs"));
+    let examples = root.join(format!("examples/day{day}"));
+    let registry = root.join("src/days.rs");
+
+    for path in [&module, &examples] {
+        if path.exists() {
+            return Err(Error::Exists(path.clone()));
+        }
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    };
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    };
+
+    let registered = register(&read(&registry)?, day).ok_or_else(|| {
+        Error::Invalid(format!(
+            "cannot register day {day} in {}",
+            registry.display()
+        ))
+    })?;
+
+    fs::create_dir_all(&examples).map_err(|source| Error::Io {
+        path: examples.clone(),
+        source,
+    })?;
+    let (example_in, example_out) = (examples.join("example.in"), examples.join("example.out"));
+    write(&example_in, EXAMPLE_IN)?;
+    write(&example_out, EXAMPLE_OUT)?;
+    write(&module, &module_source(day))?;
+    write(&registry, &registered)?;
+
+    Ok(vec![module, example_in, example_out, registry])
+}
+
+const EXAMPLE_IN: &str = "paste the example input here\n";
+
+const EXAMPLE_OUT: &str = "\
+# The answers to the example, one line per part, such as
+# part 1: 42
+";
+
+/// The source of the module for `day`.
+pub fn module_source(day: u8) -> String {
+    format!(
+        r#"use std::fmt::Display;
+
+use crate::{{Error, Solution, parse::lines}};
+use winnow::{{Parser, Result, token::take_till}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {{
+        Ok(parse.parse(input)?)
+    }}
+
+    fn part_1(input: &Self::Input) -> Result<impl Display, Error> {{
+        part_1(input)
+    }}
+
+    fn part_2(input: &Self::Input) -> Result<impl Display, Error> {{
+        part_2(input)
+    }}
+}}
+
+fn part_1(_i: &[String]) -> Result<u64, Error> {{
+    Err(Error::Unsolved)
+}}
+
+fn part_2(_i: &[String]) -> Result<u64, Error> {{
+    Err(Error::Unsolved)
+}}
+
+fn parse(i: &mut &str) -> Result<Vec<String>> {{
+    lines(take_till(1.., ['\r', '\n']).map(str::to_owned)).parse_next(i)
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/day{day}/example.in");
+
+    #[test]
+    fn test_parse() {{
+        assert_eq!(parse.parse(INPUT).unwrap().len(), INPUT.lines().count());
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `day` to the `mod` declarations and the list of days in `days_rs`,
+/// the source of `src/days.rs`, keeping both sorted: the modules by name, as
+/// rustfmt orders them, and the days by number. `None` if either list is
+/// missing or has the day already.
+pub fn register(days_rs: &str, day: u8) -> Option<String> {
+    let mut lines = days_rs.lines().map(str::to_owned).collect::<Vec<_>>();
+
+    let module = format!("day{day}");
+    let i = position(&lines, &module, |l| {
+        let m = l.strip_prefix("mod ")?.strip_suffix(';')?;
+        m.strip_prefix("day")?.parse::<u8>().ok()?;
+        Some(m.to_owned())
+    })?;
+    lines.insert(i, format!("mod {module};"));
+
+    let i = position(&lines, &day, |l| {
+        let (_, n) = l.strip_prefix("    Day::new::<day")?.split_once(">(")?;
+        n.strip_suffix("),")?.parse().ok()
+    })?;
+    lines.insert(i, format!("    Day::new::<day{day}::Day{day}>({day}),"));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Where the line for `key` goes among the lines for which `key_of` returns
+/// a key, or `None` if there are none or `key` is among them.
+fn position<K: Ord>(
+    lines: &[String],
+    key: &K,
+    key_of: impl Fn(&str) -> Option<K>,
+) -> Option<usize> {
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key_of(l)?)))
+        .collect::<Vec<_>>();
+
+    if keyed.iter().any(|(_, k)| k == key) {
+        return None;
+    }
+
+    match keyed.iter().find(|(_, k)| k > key) {
+        Some(&(i, _)) => Some(i),
+        None => Some(keyed.last()?.0 + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    const DAYS_RS: &str = "\
+mod day1;
+mod day3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(1),
+    Day::new::<day3::Day3>(3),
+];
+";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(DAYS_RS, 2).unwrap(),
+            "\
+mod day1;
+mod day2;
+mod day3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+];
+"
+        );
+
+        // rustfmt sorts modules by name, so day10 comes before day3.
+        let with_10 = register(DAYS_RS, 10).unwrap();
+        assert!(with_10.contains("mod day1;\nmod day10;\nmod day3;\n"));
+        assert!(with_10.contains("(3),\n    Day::new::<day10::Day10>(10),\n];"));
+
+        let mut days_rs = DAYS_RS.to_owned();
+        for day in [2, 20, 11, 4, 12] {
+            days_rs = register(&days_rs, day).unwrap();
+        }
+        let modules = days_rs
+            .lines()
+            .filter_map(|l| l.strip_prefix("mod ")?.strip_suffix(';'))
+            .collect::<Vec<_>>();
+        let mut sorted = modules.clone();
+        sorted.sort();
+        assert_eq!(modules, sorted);
+        assert!(
+            days_rs.contains(
+                "(3),\n    Day::new::<day4::Day4>(4),\n    Day::new::<day11::Day11>(11),\n"
+            )
+        );
+
+        assert_eq!(register(DAYS_RS, 3), None);
+        assert_eq!(register("", 3), None);
+    }
+
+    #[test]
+    fn test_create() {
+        let root = TempDir::new("scaffold");
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days.rs"), DAYS_RS).unwrap();
+
+        let created = create(&root, 2).unwrap();
+        assert_eq!(created.len(), 4);
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day2.rs")).unwrap(),
+            module_source(2)
+        );
+        assert!(root.join("examples/day2/example.out").is_file());
+        assert!(
+            fs::read_to_string(root.join("src/days.rs"))
+                .unwrap()
+                .contains("mod day2;")
+        );
+
+        assert!(matches!(create(&root, 2), Err(Error::Exists(_))));
+
+        // A day that is registered but has no files is not overwritten either.
+        assert!(matches!(create(&root, 3), Err(Error::Invalid(_))));
+        assert!(!root.join("src/days/day3.rs").exists());
+        assert!(!root.join("examples/day3").exists());
+    }
+
+    #[test]
+    fn test_module_source() {
+        let source = module_source(12);
+        assert!(source.contains("pub struct Day12;"));
+        assert!(source.contains("impl Solution for Day12 {"));
+        assert!(source.contains("\"../../examples/day12/example.in\""));
+    }
+}
@@ -0,0 +1,418 @@
+//! Downloading puzzle inputs from, and submitting answers to, the Advent of
+//! Code website.
+//!
+//! Every request is made with the user's session token, which is read from
+//! `$AOC_SESSION` or from a file, and identifies this program in its
+//! User-Agent as the website asks automated tools to do.
+
+use std::{
+    env,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    Error, Result,
+    guesses::Feedback,
+    http::{Request, Response},
+    input,
+};
+
+pub const YEAR: u16 = 2025;
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable naming the file the session token is read from.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/424ever/aoc-2025)"
+);
+
+/// The website, and who to talk to it as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    /// Where the website is, without a trailing `/`.
+    pub base_url: String,
+    /// The value of the `session` cookie, if there is one. It is only needed
+    /// once a request is made.
+    pub session: Option<String>,
+}
+
+/// Where an input is, after [`Site::fetch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was there already.
+    Cached(PathBuf),
+    /// The input was downloaded and saved.
+    Downloaded(PathBuf),
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Site {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+        }
+    }
+
+    /// The site at `$AOC_BASE_URL`, or [`DEFAULT_BASE_URL`], with the session
+    /// token from [`session`].
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .filter(|u| !u.is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned());
+
+        Ok(Site::new(&base_url, session()?))
+    }
+
+    pub fn input_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+
+    /// Downloads the input of `day`.
+    pub fn download_input(&self, day: u8) -> Result<String> {
+        let url = self.input_url(day);
+        let input = self.send(Request::get(&url))?;
+
+        if input.is_empty() {
+            return Err(Error::Http {
+                url,
+                message: "the input is empty".to_owned(),
+            });
+        }
+
+        Ok(input)
+    }
+
+    /// Makes sure the input of `day` is in one of the locations searched by
+    /// [`input::resolve`]. An input that is found there already is never
+    /// downloaded again; otherwise it is saved to the first of the
+    /// [`input::candidates`].
+    pub fn fetch(&self, day: u8) -> Result<Fetched> {
+        self.fetch_into(day, &input::candidates(day, |k| env::var_os(k)))
+    }
+
+    fn fetch_into(&self, day: u8, candidates: &[PathBuf]) -> Result<Fetched> {
+        if let Some(path) = candidates.iter().find(|p| p.is_file()) {
+            return Ok(Fetched::Cached(path.clone()));
+        }
+
+        let path = candidates
+            .first()
+            .expect("the repository is always a candidate");
+        let input = self.download_input(day)?;
+        write_atomically(path, &input).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+
+        Ok(Fetched::Downloaded(path.clone()))
+    }
+
+    pub fn answer_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/answer", self.base_url)
+    }
+
+    /// Submits `answer` to `part` of `day` and returns the verdict. Being
+    /// told to wait, or that the part is solved or locked, is an error.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Feedback> {
+        let url = self.answer_url(day);
+        let level = part.to_string();
+        let page = self.send(Request::post_form(
+            &url,
+            &[("level", &level), ("answer", answer)],
+        ))?;
+
+        feedback(&page).map_err(|message| Error::Http { url, message })
+    }
+
+    /// Sends `request` with the session cookie and User-Agent, and returns
+    /// the body of a successful response.
+    fn send(&self, request: Request) -> Result<String> {
+        let session = self.session.as_deref().ok_or(Error::NoSession)?;
+        let request = request
+            .header("Cookie", format!("session={session}"))
+            .header("User-Agent", USER_AGENT);
+
+        match request.send()? {
+            Response { status: 200, body } => Ok(body),
+            Response { status, body } => Err(Error::Http {
+                url: request.url.to_owned(),
+                message: match body.lines().next().map(str::trim) {
+                    Some(line) if !line.is_empty() => format!("HTTP {status}: {line}"),
+                    _ => format!("HTTP {status}"),
+                },
+            }),
+        }
+    }
+}
+
+/// Reads the verdict from the page returned for a submitted answer.
+fn feedback(page: &str) -> Result<Feedback, String> {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Ok(Feedback::Correct)
+    } else if text.contains("That's not the right answer") {
+        Ok(if text.contains("too high") {
+            Feedback::TooHigh
+        } else if text.contains("too low") {
+            Feedback::TooLow
+        } else {
+            Feedback::Wrong
+        })
+    } else if text.is_empty() {
+        Err("unexpected response".to_owned())
+    } else {
+        Err(format!("the website says: {text}"))
+    }
+}
+
+/// The text of the `<article>` of `page`, or of the whole page if it has
+/// none, without markup and with whitespace collapsed.
+fn article_text(page: &str) -> String {
+    let article = page.split_once("<article>").map_or(page, |(_, a)| {
+        a.split_once("</article>").map_or(a, |(a, _)| a)
+    });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The session token from `$AOC_SESSION`, or else from [`session_file`], with
+/// surrounding whitespace removed. `None` if neither has one.
+pub fn session() -> Result<Option<String>> {
+    if let Some(s) = env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()) {
+        return Ok(Some(s.trim().to_owned()));
+    }
+
+    let path = session_file();
+    match fs::read_to_string(&path) {
+        Ok(s) if !s.trim().is_empty() => Ok(Some(s.trim().to_owned())),
+        Ok(_) => Ok(None),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(Error::Io { path, source }),
+    }
+}
+
+/// The file the session token is kept in: `$AOC_SESSION_FILE`, or `session`
+/// next to the inputs in the per-user data directory.
+pub fn session_file() -> PathBuf {
+    session_file_from(|k| env::var_os(k))
+}
+
+fn session_file_from(var: impl Fn(&str) -> Option<OsString>) -> PathBuf {
+    if let Some(f) = var(SESSION_FILE_VAR).filter(|f| !f.is_empty()) {
+        return f.into();
+    }
+
+    input::data_dir(var)
+        .map(|d| d.join(env!("CARGO_PKG_NAME")))
+        .unwrap_or_default()
+        .join("session")
+}
+
+/// Writes `contents` to `path` so that there is never a partial file at
+/// `path`, creating its directory if needed.
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(dir)?;
+
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".download");
+    let tmp = dir.join(name);
+
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    /// Serves `responses` to one request each on a local port. Returns the
+    /// base URL and a handle that yields the requests received.
+    fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(v) = line.strip_prefix("Content-Length: ") {
+                        length = v.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request.push_str(&String::from_utf8(body).unwrap());
+
+                (&stream).write_all(response.as_bytes()).unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (url, server) = serve(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\nL68\nR4".to_owned(),
+        ]);
+        let site = Site::new(&format!("{url}/"), Some("c0ffee".to_owned()));
+        let dir = TempDir::new("fetch");
+        let candidates = [dir.join("inputs/day1"), dir.join("repo/day1")];
+
+        assert_eq!(
+            site.fetch_into(1, &candidates).unwrap(),
+            Fetched::Downloaded(candidates[0].clone())
+        );
+        assert_eq!(fs::read_to_string(&candidates[0]).unwrap(), "L68\nR4");
+        assert_eq!(
+            site.fetch_into(1, &candidates).unwrap(),
+            Fetched::Cached(candidates[0].clone())
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=c0ffee\r\n"));
+        assert!(request.contains(&format!("\r\nUser-Agent: {USER_AGENT}\r\n")));
+    }
+
+    #[test]
+    fn test_fetch_cached() {
+        let dir = TempDir::new("fetch-cached");
+        let candidates = [dir.join("inputs/day3"), dir.join("repo/day3")];
+        fs::create_dir_all(dir.join("repo")).unwrap();
+        fs::write(&candidates[1], "987").unwrap();
+
+        // Nothing listens there, and there is no session.
+        let site = Site::new("http://127.0.0.1:9", None);
+        assert_eq!(
+            site.fetch_into(3, &candidates).unwrap(),
+            Fetched::Cached(candidates[1].clone())
+        );
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (url, server) = serve(vec![
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 26\r\n\r\nPlease don't repeatedly..."
+                .to_owned(),
+        ]);
+        let dir = TempDir::new("fetch-errors");
+        let candidates = [dir.join("day12")];
+
+        let e = Site::new(&url, Some("c0ffee".to_owned()))
+            .fetch_into(12, &candidates)
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            format!(
+                "request to {url}/2025/day/12/input failed: HTTP 404: Please don't repeatedly..."
+            )
+        );
+        assert!(!dir.exists());
+        server.join().unwrap();
+
+        let e = Site::new(&url, None).fetch_into(12, &candidates);
+        assert!(matches!(e, Err(Error::NoSession)));
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = |article: &str| {
+            let page = format!("<html><main><article><p>{article}</p></article></main></html>");
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{page}",
+                page.len()
+            )
+        };
+        let (url, server) = serve(vec![
+            page(
+                "That's not the right answer; your answer is too high. <a href=\"/\">[Return]</a>",
+            ),
+            page("That's not the right answer. If you're stuck, ..."),
+            page("That's the right answer! You are one gold star closer."),
+            page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.",
+            ),
+        ]);
+        let site = Site::new(&url, Some("c0ffee".to_owned()));
+
+        assert_eq!(site.submit(1, 2, "1 2&3").unwrap(), Feedback::TooHigh);
+        assert_eq!(site.submit(1, 2, "4").unwrap(), Feedback::Wrong);
+        assert_eq!(site.submit(1, 2, "5").unwrap(), Feedback::Correct);
+        assert_eq!(
+            site.submit(1, 2, "6").unwrap_err().to_string(),
+            format!(
+                "request to {url}/2025/day/1/answer failed: the website says: You gave an answer \
+                 too recently; you have to wait after submitting an answer before trying again. \
+                 You have 37s left to wait."
+            )
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=c0ffee\r\n"));
+        assert!(requests[0].contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=1+2%263"));
+    }
+
+    #[test]
+    fn test_session_file() {
+        assert_eq!(
+            session_file_from(|k| (k == SESSION_FILE_VAR).then(|| "/secret".into())),
+            PathBuf::from("/secret")
+        );
+        assert_eq!(
+            session_file_from(|k| (k == "XDG_DATA_HOME").then(|| "/data".into())),
+            PathBuf::from("/data/aoc-2025/session")
+        );
+    }
+}
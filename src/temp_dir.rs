@@ -0,0 +1,54 @@
+//! Scratch directories for tests.
+
+use std::{
+    env, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// A directory for one test, which the test creates itself. It is removed
+/// again when dropped, even if the test fails.
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    /// A directory named after the test, as `name`, so that tests running at
+    /// the same time do not share one. Anything left there by an earlier run
+    /// is removed.
+    pub(crate) fn new(name: &str) -> Self {
+        let dir = env::temp_dir().join(format!("aoc-2025-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        TempDir(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir() {
+        let dir = TempDir::new("temp-dir");
+        assert!(!dir.exists());
+
+        fs::create_dir_all(dir.join("a")).unwrap();
+        fs::write(dir.join("a/b"), "").unwrap();
+        let path = dir.to_owned();
+        drop(dir);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,138 @@
+//! Disjoint sets of the indices `0..n`.
+
+/// A union-find structure with path compression and union by size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// The size of each component, valid at its root only.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Creates `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set containing `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is out of bounds.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns whether they were
+    /// separate before.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (big, small) = if self.size[a] < self.size[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of disjoint sets, in O(1).
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|&(x, &p)| x == p)
+            .map(|(x, _)| self.size[x])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut uf = UnionFind::new(6);
+        assert_eq!(uf.components(), 6);
+
+        assert!(uf.union(0, 1));
+        assert!(uf.union(2, 3));
+        assert!(uf.union(1, 3));
+        assert!(!uf.union(0, 2));
+
+        assert!(uf.connected(0, 3));
+        assert!(!uf.connected(0, 4));
+        assert_eq!(uf.components(), 3);
+        assert_eq!(uf.size(2), 4);
+        assert_eq!(uf.size(5), 1);
+
+        let mut sizes = uf.sizes().collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+
+    #[test]
+    fn test_path_compression() {
+        // Merging sets of equal size builds a tree of depth log n.
+        let mut uf = UnionFind::new(128);
+        for step in (0..7).map(|n| 1 << n) {
+            for x in (0..128).step_by(2 * step) {
+                uf.union(x, x + step);
+            }
+        }
+        assert!(uf.parent.iter().any(|&p| uf.parent[p] != p));
+
+        let root = uf.find(0);
+        for x in 0..128 {
+            assert_eq!(uf.find(x), root);
+        }
+        assert!(uf.parent.iter().all(|&p| p == root));
+        assert_eq!((uf.size(99), uf.components()), (128, 1));
+    }
+}
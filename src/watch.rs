@@ -0,0 +1,270 @@
+//! Re-running a day whenever its input or examples change.
+//!
+//! Files are polled for changes to their modification time and size, so this
+//! works the same everywhere without a file system notification API. The
+//! answers are compared with those of the previous run to show what changed.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{Error, Result, days::Day, input, read_input};
+
+/// What is known about a file to tell whether it changed, `None` if it does
+/// not exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Watches the files of one day.
+pub struct Watcher {
+    day: &'static Day,
+    /// The input file, or `None` for the one found by [`input::resolve`].
+    input: Option<PathBuf>,
+    /// The directory with the examples of the day.
+    examples: PathBuf,
+    /// The source of the day, which needs a rebuild to take effect.
+    source: Option<PathBuf>,
+    stamps: BTreeMap<PathBuf, Stamp>,
+    /// The answers of the previous run, as `(label, answer)`.
+    answers: Option<Vec<(String, String)>>,
+}
+
+impl Watcher {
+    pub fn new(
+        day: &'static Day,
+        input: Option<PathBuf>,
+        examples: PathBuf,
+        source: Option<PathBuf>,
+    ) -> Self {
+        Watcher {
+            day,
+            input,
+            examples,
+            source,
+            stamps: BTreeMap::new(),
+            answers: None,
+        }
+    }
+
+    /// A watcher for the files of `day` in this repository.
+    pub fn for_day(day: &'static Day, input: Option<PathBuf>) -> Self {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let n = day.number;
+
+        Watcher::new(
+            day,
+            input,
+            root.join(format!("examples/day{n}")),
+            Some(root.join(format!("src/days/day{n}.rs"))),
+        )
+    }
+
+    /// Checks the files once. On the first call, or if any of them changed,
+    /// solves the day again and returns the answers, noting those that
+    /// differ from the previous run.
+    pub fn poll(&mut self) -> Option<String> {
+        let stamps = self
+            .files()
+            .into_iter()
+            .map(|p| {
+                let s = stamp(&p);
+                (p, s)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let get = |stamps: &BTreeMap<PathBuf, Stamp>, p| stamps.get(p).copied().flatten();
+        let changed = stamps
+            .keys()
+            .chain(self.stamps.keys())
+            .filter(|p| get(&stamps, *p) != get(&self.stamps, *p))
+            .collect::<BTreeSet<_>>();
+
+        let first = self.answers.is_none();
+        if !first && changed.is_empty() {
+            return None;
+        }
+
+        let mut out = format!("day {}", self.day.number);
+        if !first {
+            let names = changed.iter().map(|p| p.display().to_string());
+            let _ = write!(out, ": {} changed", names.collect::<Vec<_>>().join(", "));
+        }
+        out.push('\n');
+        if !first && self.source.as_ref().is_some_and(|s| changed.contains(s)) {
+            out.push_str("the source changed, rebuild to run the new code\n");
+        }
+
+        let answers = self.solve();
+        let previous = self.answers.take().unwrap_or_default();
+        for (label, answer) in &answers {
+            match previous.iter().find(|(l, _)| l == label) {
+                Some((_, old)) if old != answer => {
+                    let _ = writeln!(out, "{label}: {answer} (was {old})");
+                }
+                _ => {
+                    let _ = writeln!(out, "{label}: {answer}");
+                }
+            }
+        }
+        for (label, old) in &previous {
+            if !answers.iter().any(|(l, _)| l == label) {
+                let _ = writeln!(out, "{label}: gone (was {old})");
+            }
+        }
+
+        self.stamps = stamps;
+        self.answers = Some(answers);
+        Some(out)
+    }
+
+    /// Every file that is watched, whether it exists or not.
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = match &self.input {
+            Some(p) => vec![p.clone()],
+            None => input::candidates(self.day.number, |k| env::var_os(k)),
+        };
+        for example in examples(&self.examples) {
+            files.push(example.with_extension("out"));
+            files.push(example);
+        }
+        files.extend(self.source.clone());
+
+        files
+    }
+
+    /// The answers for the input and for every example, as `(label, answer)`.
+    fn solve(&self) -> Vec<(String, String)> {
+        let mut answers = vec![];
+
+        let input = input::resolve(self.day.number, self.input.as_deref())
+            .and_then(|p| Ok((read_input(&p)?, p)));
+        match input.and_then(|(input, path)| self.solve_both(&input, &path)) {
+            Ok(parts) => answers.extend(parts.map(|(p, a)| (format!("part {p}"), a))),
+            Err(e) => answers.push(("input".to_owned(), format!("error: {e}"))),
+        }
+
+        for example in examples(&self.examples) {
+            let name = example.file_stem().unwrap_or_default().to_string_lossy();
+            let expected = fs::read_to_string(example.with_extension("out")).unwrap_or_default();
+
+            let solved = read_input(&example).and_then(|i| self.solve_both(&i, &example));
+            let parts = match solved {
+                Ok(parts) => parts,
+                Err(e) => {
+                    answers.push((name.into_owned(), format!("error: {e}")));
+                    continue;
+                }
+            };
+
+            for (part, mut answer) in parts {
+                match expected_answer(&expected, part) {
+                    Some(e) if e == answer => answer.push_str(" (ok)"),
+                    Some(e) => {
+                        let _ = write!(answer, " (expected {e})");
+                    }
+                    None => {}
+                }
+                answers.push((format!("{name} part {part}"), answer));
+            }
+        }
+
+        answers
+    }
+
+    /// Parses `input`, read from `path`, and solves both parts.
+    fn solve_both(&self, input: &str, path: &Path) -> Result<[(u8, String); 2]> {
+        let parsed = self.day.parse(input).map_err(|e| e.in_file(path))?;
+
+        Ok([1, 2].map(|p| {
+            let answer = match self.day.solve(p, &parsed) {
+                Ok(a) => a,
+                Err(Error::Unsolved) => "not solved yet".to_owned(),
+                Err(e) => format!("error: {e}"),
+            };
+            (p, answer)
+        }))
+    }
+}
+
+fn stamp(path: &Path) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// The example inputs in `dir`, sorted.
+fn examples(dir: &Path) -> Vec<PathBuf> {
+    let mut examples = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "in"))
+        .collect::<Vec<_>>();
+    examples.sort();
+
+    examples
+}
+
+/// The answer to `part` in the expected answers of an example, in the format
+/// of `tests/examples.rs`.
+fn expected_answer(expected: &str, part: u8) -> Option<&str> {
+    expected.lines().map(str::trim).find_map(|l| {
+        let (p, a) = l.strip_prefix("part ")?.split_once(": ")?;
+        (p.parse() == Ok(part)).then_some(a)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, temp_dir::TempDir};
+
+    #[test]
+    fn test_poll() {
+        let dir = TempDir::new("watch");
+        fs::create_dir_all(dir.join("examples")).unwrap();
+        let input = dir.join("day1");
+        fs::write(&input, "L68\nL30\nR48\n").unwrap();
+        fs::write(dir.join("examples/a.in"), "R50\n").unwrap();
+        fs::write(dir.join("examples/a.out"), "part 1: 1\npart 2: 2\n").unwrap();
+
+        let mut w = Watcher::new(
+            days::get(1).unwrap(),
+            Some(input.clone()),
+            dir.join("examples"),
+            None,
+        );
+        assert_eq!(
+            w.poll().unwrap(),
+            "day 1\npart 1: 1\npart 2: 2\na part 1: 1 (ok)\na part 2: 1 (expected 2)\n"
+        );
+        assert_eq!(w.poll(), None);
+
+        fs::write(&input, "L68\nL30\nR48\nL100\n").unwrap();
+        assert_eq!(
+            w.poll().unwrap(),
+            format!(
+                "day 1: {} changed\npart 1: 2 (was 1)\npart 2: 3 (was 2)\n\
+                 a part 1: 1 (ok)\na part 2: 1 (expected 2)\n",
+                input.display()
+            )
+        );
+
+        fs::write(&input, "X").unwrap();
+        let out = w.poll().unwrap();
+        assert!(out.contains("\ninput: error: "));
+        assert!(out.contains("\npart 1: gone (was 2)\n"));
+    }
+
+    #[test]
+    fn test_expected_answer() {
+        let expected = "# comment\npart 2: 6\npart 1: 3 4\n";
+
+        assert_eq!(expected_answer(expected, 1), Some("3 4"));
+        assert_eq!(expected_answer(expected, 2), Some("6"));
+        assert_eq!(expected_answer("", 1), None);
+    }
+}
@@ -0,0 +1,44 @@
+//! Checks every day against the examples in `examples/day<n>/`.
+//!
+//! An example is a pair of files: `<name>.in` with the puzzle input, and
+//! `<name>.out` with the expected answers in the runner's output format,
+//! `part <n>: <answer>`, one per line. Parts without a line are not checked,
+//! and lines starting with `#` are comments. The build script generates a
+//! test for each pair.
+
+use aoc_2025::days::{self, DAYS};
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+fn check(day: u8, input: &str, expected: &str) {
+    let day = days::get(day).unwrap_or_else(|| panic!("day {day} is not registered"));
+    let parsed = day.parse(input).unwrap_or_else(|e| panic!("{e}"));
+
+    for line in expected.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (part, answer) = line
+            .strip_prefix("part ")
+            .and_then(|l| l.split_once(": "))
+            .and_then(|(p, a)| Some((p.parse().ok().filter(|p| matches!(p, 1 | 2))?, a)))
+            .unwrap_or_else(|| panic!("expected `part <1|2>: <answer>`, got `{line}`"));
+
+        match day.solve(part, &parsed) {
+            Ok(a) => assert_eq!(a, answer, "part {part}"),
+            Err(e) => panic!("part {part}: {e}"),
+        }
+    }
+}
+
+#[test]
+fn every_day_has_an_example() {
+    for day in DAYS {
+        assert!(
+            EXAMPLE_DAYS.contains(&day.number),
+            "day {} has no examples",
+            day.number
+        );
+    }
+}